@@ -6,7 +6,48 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use syn::{punctuated::Punctuated, token::Comma, GenericArgument};
+use quote::quote_spanned;
+use syn::{punctuated::Punctuated, spanned::Spanned, token::Comma, GenericArgument};
+
+mod collection;
+mod container;
+mod getter;
+mod mutator;
+mod setter;
+mod traits;
+
+pub(crate) use self::{
+    collection::derive_collection_methods,
+    container::{
+        derive_builder, derive_field_names, derive_fields_const, derive_fields_mut, derive_merge,
+        derive_new, derive_parts, derive_patch, derive_reset_all, derive_sort_key, derive_trait,
+    },
+    getter::derive_getters,
+    mutator::derive_mutators,
+    setter::derive_setters,
+    traits::{
+        derive_as_conv, derive_borrow, derive_debug, derive_default, derive_deref, derive_display,
+        derive_extend, derive_index, derive_into_iterator, implement_traits,
+    },
+};
+
+const NOT_COPY_TYPES: &[&str] = &[
+    "PathBuf",
+    "LinkedList",
+    "HashMap",
+    "BTreeMap",
+    "HashSet",
+    "BTreeSet",
+    "BinaryHeap",
+    "Box",
+    "Rc",
+    "Arc",
+    "Cow",
+];
+
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
 
 pub(crate) enum GetType {
     Ref,
@@ -15,6 +56,9 @@ pub(crate) enum GetType {
     String_,
     Slice(syn::TypeSlice),
     Option_(Punctuated<GenericArgument, Comma>),
+    TryRef,
+    Bytes,
+    Custom(syn::Path, Box<syn::Type>),
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -27,6 +71,20 @@ pub(crate) enum ClrMethod {
     None_,
 }
 
+/// The collections which are accessed by keys.
+pub(crate) enum KeyedType {
+    HashMap(syn::Type, syn::Type),
+    BTreeMap(syn::Type, syn::Type),
+    HashSet(syn::Type),
+    BTreeSet(syn::Type),
+}
+
+/// The locks which could be written through a shared reference.
+pub(crate) enum LockType {
+    Mutex(syn::Type),
+    RwLock(syn::Type),
+}
+
 pub(crate) enum FieldType {
     Number,
     Boolean,
@@ -34,6 +92,7 @@ pub(crate) enum FieldType {
     String_,
     Array(syn::TypeArray),
     Vector(syn::Type),
+    VecDeque(syn::Type),
     Option_(Punctuated<GenericArgument, Comma>),
     Unhandled(Option<String>),
 }
@@ -70,7 +129,7 @@ impl GetType {
                 }
                 GetType::Option_(inner_type.clone())
             }
-            FieldType::Unhandled(_) => GetType::Ref,
+            FieldType::VecDeque(_) | FieldType::Unhandled(_) => GetType::Ref,
         }
     }
 }
@@ -81,7 +140,9 @@ impl ClrMethod {
             FieldType::Number => ClrMethod::SetZero,
             FieldType::Option_(_) => ClrMethod::SetNone,
             FieldType::Boolean | FieldType::Character => ClrMethod::SetDefault,
-            FieldType::String_ | FieldType::Vector(_) => ClrMethod::CallClear,
+            FieldType::String_ | FieldType::Vector(_) | FieldType::VecDeque(_) => {
+                ClrMethod::CallClear
+            }
             FieldType::Array(_) => ClrMethod::FillWithDefault,
             FieldType::Unhandled(Some(ref type_name)) => match type_name.as_str() {
                 "String" | "PathBuf" | "Vec" | "VecDeque" | "LinkedList" | "HashMap"
@@ -93,6 +154,118 @@ impl ClrMethod {
     }
 }
 
+impl KeyedType {
+    pub(crate) fn from_type(ty: &syn::Type) -> Option<Self> {
+        if let syn::Type::Path(type_path) = ty {
+            let seg = type_path.path.segments.last()?;
+            let types = if let syn::PathArguments::AngleBracketed(inner) = &seg.arguments {
+                inner
+                    .args
+                    .iter()
+                    .filter_map(|arg| {
+                        if let syn::GenericArgument::Type(inner_type) = arg {
+                            Some(inner_type.clone())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                return None;
+            };
+            match (seg.ident.to_string().as_ref(), types.len()) {
+                ("HashMap", 2) | ("HashMap", 3) => {
+                    Some(KeyedType::HashMap(types[0].clone(), types[1].clone()))
+                }
+                ("BTreeMap", 2) => Some(KeyedType::BTreeMap(types[0].clone(), types[1].clone())),
+                ("HashSet", 1) | ("HashSet", 2) => Some(KeyedType::HashSet(types[0].clone())),
+                ("BTreeSet", 1) => Some(KeyedType::BTreeSet(types[0].clone())),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The type of the keys.
+    pub(crate) fn key_type(&self) -> &syn::Type {
+        match self {
+            KeyedType::HashMap(key_type, _)
+            | KeyedType::BTreeMap(key_type, _)
+            | KeyedType::HashSet(key_type)
+            | KeyedType::BTreeSet(key_type) => key_type,
+        }
+    }
+
+    /// The type of the values, only for maps.
+    pub(crate) fn value_type(&self) -> Option<&syn::Type> {
+        match self {
+            KeyedType::HashMap(_, value_type) | KeyedType::BTreeMap(_, value_type) => {
+                Some(value_type)
+            }
+            KeyedType::HashSet(_) | KeyedType::BTreeSet(_) => None,
+        }
+    }
+
+    /// The type of the entries, only for maps.
+    pub(crate) fn entry_type(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            KeyedType::HashMap(key_type, value_type) => Some(quote::quote!(
+                ::std::collections::hash_map::Entry<'_, #key_type, #value_type>
+            )),
+            KeyedType::BTreeMap(key_type, value_type) => Some(quote::quote!(
+                ::std::collections::btree_map::Entry<'_, #key_type, #value_type>
+            )),
+            KeyedType::HashSet(_) | KeyedType::BTreeSet(_) => None,
+        }
+    }
+
+    /// The trait bounds which are required to look up the keys.
+    pub(crate) fn lookup_bounds(&self) -> proc_macro2::TokenStream {
+        match self {
+            KeyedType::HashMap(..) | KeyedType::HashSet(_) => {
+                quote::quote!(::core::hash::Hash + ::core::cmp::Eq)
+            }
+            KeyedType::BTreeMap(..) | KeyedType::BTreeSet(_) => quote::quote!(::core::cmp::Ord),
+        }
+    }
+}
+
+impl LockType {
+    pub(crate) fn from_type(ty: &syn::Type) -> Option<Self> {
+        FieldType::pointer_inner_type(ty, &["Mutex"])
+            .map(LockType::Mutex)
+            .or_else(|| FieldType::pointer_inner_type(ty, &["RwLock"]).map(LockType::RwLock))
+    }
+
+    /// The type of the locked value.
+    pub(crate) fn inner_type(&self) -> &syn::Type {
+        match self {
+            LockType::Mutex(inner_type) | LockType::RwLock(inner_type) => inner_type,
+        }
+    }
+
+    /// The method which acquires the lock for writing.
+    pub(crate) fn lock_method(&self) -> proc_macro2::TokenStream {
+        match self {
+            LockType::Mutex(_) => quote::quote!(lock),
+            LockType::RwLock(_) => quote::quote!(write),
+        }
+    }
+
+    /// The error type when the lock is poisoned.
+    pub(crate) fn error_type(&self) -> proc_macro2::TokenStream {
+        match self {
+            LockType::Mutex(inner_type) => quote::quote!(
+                ::std::sync::PoisonError<::std::sync::MutexGuard<'_, #inner_type>>
+            ),
+            LockType::RwLock(inner_type) => quote::quote!(
+                ::std::sync::PoisonError<::std::sync::RwLockWriteGuard<'_, #inner_type>>
+            ),
+        }
+    }
+}
+
 impl FieldType {
     pub(crate) fn from_type(ty: &syn::Type) -> Self {
         match ty {
@@ -119,6 +292,19 @@ impl FieldType {
                                 unreachable!()
                             }
                         }
+                        "VecDeque" => {
+                            if let syn::PathArguments::AngleBracketed(inner) =
+                                &type_path.path.segments[0].arguments
+                            {
+                                if let syn::GenericArgument::Type(ref inner_type) = inner.args[0] {
+                                    FieldType::VecDeque(inner_type.clone())
+                                } else {
+                                    unreachable!()
+                                }
+                            } else {
+                                unreachable!()
+                            }
+                        }
                         "Option" => {
                             if let syn::PathArguments::AngleBracketed(inner) =
                                 &type_path.path.segments[0].arguments
@@ -141,4 +327,144 @@ impl FieldType {
             _ => FieldType::Unhandled(None),
         }
     }
+
+    /// Checks if the type is a primitive integer.
+    pub(crate) fn is_integer(ty: &syn::Type) -> bool {
+        if let syn::Type::Path(type_path) = ty {
+            type_path.qself.is_none()
+                && type_path
+                    .path
+                    .get_ident()
+                    .map(|ident| INTEGER_TYPES.contains(&ident.to_string().as_str()))
+                    .unwrap_or(false)
+        } else {
+            false
+        }
+    }
+
+    /// The inner type, for `Box<T>`.
+    pub(crate) fn box_inner_type(ty: &syn::Type) -> Option<syn::Type> {
+        Self::pointer_inner_type(ty, &["Box"])
+    }
+
+    /// The inner type, for `Box<T>` where `T` is sized.
+    pub(crate) fn sized_box_inner_type(ty: &syn::Type) -> Option<syn::Type> {
+        Self::box_inner_type(ty).filter(Self::is_sized)
+    }
+
+    /// The inner type, for `Arc<T>` and `Rc<T>` where `T` is sized.
+    pub(crate) fn shared_inner_type(ty: &syn::Type) -> Option<syn::Type> {
+        Self::pointer_inner_type(ty, &["Arc", "Rc"]).filter(Self::is_sized)
+    }
+
+    // The `str`, slices and trait objects are not sized, other types are treated as sized.
+    fn is_sized(ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Path(type_path) => !type_path.path.is_ident("str"),
+            syn::Type::Slice(_) | syn::Type::TraitObject(_) => false,
+            _ => true,
+        }
+    }
+
+    /// The owned type, for `Cow<'a, B>`.
+    ///
+    /// It's `String` for `str`, `Vec<T>` for `[T]`, or `<B as ToOwned>::Owned` for others.
+    pub(crate) fn cow_owned_type(ty: &syn::Type) -> Option<proc_macro2::TokenStream> {
+        Self::pointer_inner_type(ty, &["Cow"]).map(|inner_type| match inner_type {
+            syn::Type::Path(ref type_path) if type_path.path.is_ident("str") => {
+                quote::quote!(String)
+            }
+            syn::Type::Slice(syn::TypeSlice { elem, .. }) => quote::quote!(Vec<#elem>),
+            _ => quote::quote!(<#inner_type as ::std::borrow::ToOwned>::Owned),
+        })
+    }
+
+    fn pointer_inner_type(ty: &syn::Type, pointer_names: &[&str]) -> Option<syn::Type> {
+        if let syn::Type::Path(type_path) = ty {
+            let seg = type_path.path.segments.last()?;
+            if type_path.qself.is_some() || !pointer_names.contains(&seg.ident.to_string().as_str())
+            {
+                return None;
+            }
+            if let syn::PathArguments::AngleBracketed(inner) = &seg.arguments {
+                // Skip the lifetimes, such as `'a` in `Cow<'a, B>`.
+                return inner.args.iter().find_map(|arg| {
+                    if let syn::GenericArgument::Type(inner_type) = arg {
+                        Some(inner_type.clone())
+                    } else {
+                        None
+                    }
+                });
+            }
+        }
+        None
+    }
+
+    /// Checks if the type is `Vec<u8>`.
+    pub(crate) fn is_bytes(&self) -> bool {
+        if let FieldType::Vector(syn::Type::Path(type_path)) = self {
+            type_path.qself.is_none() && type_path.path.is_ident("u8")
+        } else {
+            false
+        }
+    }
+
+    /// The inner type, for `Option<T>`.
+    pub(crate) fn option_inner_type(&self) -> Option<syn::Type> {
+        if let FieldType::Option_(inner_type) = self {
+            if let Some(syn::GenericArgument::Type(inner_type)) = inner_type.first() {
+                return Some(inner_type.clone());
+            }
+        }
+        None
+    }
+
+    /// Checks if the type is known as a type which doesn't implement `Copy`.
+    pub(crate) fn is_not_copy(&self) -> bool {
+        match self {
+            FieldType::String_ | FieldType::Vector(_) | FieldType::VecDeque(_) => true,
+            FieldType::Option_(inner_type) => {
+                if let Some(syn::GenericArgument::Type(inner_type)) = inner_type.first() {
+                    FieldType::from_type(inner_type).is_not_copy()
+                } else {
+                    false
+                }
+            }
+            FieldType::Unhandled(Some(ref type_name)) => {
+                NOT_COPY_TYPES.contains(&type_name.as_str())
+            }
+            _ => false,
+        }
+    }
+
+    /// The type of the items, for the fields which could be sliced.
+    pub(crate) fn slice_item_type(&self) -> Option<syn::Type> {
+        match self {
+            FieldType::Array(type_array) => Some((*type_array.elem).clone()),
+            FieldType::Vector(inner_type) => Some(inner_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// The type of the items, for the fields which could be indexed by `usize`.
+    pub(crate) fn item_type(&self) -> Option<syn::Type> {
+        match self {
+            FieldType::Array(type_array) => Some((*type_array.elem).clone()),
+            FieldType::Vector(inner_type) | FieldType::VecDeque(inner_type) => {
+                Some(inner_type.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Asserts that the field type implements the trait, the error will point to the field type.
+pub(crate) fn assert_trait(
+    field_type: &syn::Type,
+    trait_path: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote_spanned!(field_type.span()=>
+        fn assert_trait<PropertyFieldType: ?Sized + #trait_path>() {}
+        assert_trait::<#field_type>();
+    )
 }
@@ -0,0 +1,707 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::{quote, quote_spanned};
+use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+
+use crate::{
+    generate::{assert_trait, FieldType, KeyedType},
+    parse::{ExtraOptionConf, FieldDef, MethodNameConf},
+};
+
+/// The opt-in methods for the collections and the `Option` fields.
+pub(crate) fn derive_collection_methods(
+    field: &FieldDef,
+) -> ParseResult<Vec<proc_macro2::TokenStream>> {
+    let mut property = Vec::new();
+    let field_type = &field.ty;
+    let field_name = &field.ident;
+    let field_conf = &field.conf;
+    let prop_field_type = FieldType::from_type(field_type);
+    let option_inner_type = prop_field_type.option_inner_type();
+    if let Some(visibility) = field_conf.push.vis.to_ts() {
+        let push = match prop_field_type {
+            FieldType::Vector(ref item_type) => Some((item_type, quote!(push))),
+            FieldType::VecDeque(ref item_type) => Some((item_type, quote!(push_back))),
+            _ => None,
+        };
+        if let Some((item_type, push)) = field_conf.push.explicit.check(
+            push,
+            "the `push` method is only supported for `Vec` and `VecDeque`",
+        )? {
+            let method_name = field_conf.push.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name<T: Into<#item_type>>(&mut self, val: T) -> &mut Self {
+                    self.#field_name.#push(val.into());
+                    self
+                }
+            ));
+        }
+    }
+    for (conf, method, param_type, call) in [
+        (
+            &field_conf.push_str,
+            "push_str",
+            quote!(impl AsRef<str>),
+            quote!(push_str(val.as_ref())),
+        ),
+        (
+            &field_conf.push_char,
+            "push_char",
+            quote!(char),
+            quote!(push(val)),
+        ),
+    ]
+    .iter()
+    {
+        let visibility = if let Some(visibility) = conf.vis.to_ts() {
+            visibility
+        } else {
+            continue;
+        };
+        let string = match prop_field_type {
+            FieldType::String_ => Some(()),
+            _ => None,
+        };
+        if conf
+            .explicit
+            .check(
+                string,
+                &format!("the `{}` method is only supported for `String`", method),
+            )?
+            .is_some()
+        {
+            let method_name = conf.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, val: #param_type) -> &mut Self {
+                    self.#field_name.#call;
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.pop.vis.to_ts() {
+        let pop = match prop_field_type {
+            FieldType::Vector(ref item_type) => Some((item_type, quote!(pop))),
+            FieldType::VecDeque(ref item_type) => Some((item_type, quote!(pop_back))),
+            _ => None,
+        };
+        if let Some((item_type, pop)) = field_conf.pop.explicit.check(
+            pop,
+            "the `pop` method is only supported for `Vec` and `VecDeque`",
+        )? {
+            let method_name = field_conf.pop.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> Option<#item_type> {
+                    self.#field_name.#pop()
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.iter_mut.vis.to_ts() {
+        let item_type = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::Array(type_array), _) => {
+                let item_type = &type_array.elem;
+                Some(quote!(&mut #item_type))
+            }
+            (FieldType::Vector(item_type), _) | (FieldType::VecDeque(item_type), _) => {
+                Some(quote!(&mut #item_type))
+            }
+            (_, Some(keyed_type)) => keyed_type.value_type().map(|value_type| {
+                let key_type = keyed_type.key_type();
+                quote!((&#key_type, &mut #value_type))
+            }),
+            _ => None,
+        };
+        if let Some(item_type) = field_conf.iter_mut.explicit.check(
+            item_type,
+            "the `iter_mut` method is only supported for arrays, `Vec`, `VecDeque` and maps",
+        )? {
+            let method_name = field_conf.iter_mut.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> impl Iterator<Item = #item_type> + '_ {
+                    self.#field_name.iter_mut()
+                }
+            ));
+        }
+    }
+    if field_conf.keys.vis.to_ts().is_some()
+        || field_conf.values.vis.to_ts().is_some()
+        || field_conf.values_mut.vis.to_ts().is_some()
+    {
+        let map_types = KeyedType::from_type(field_type).and_then(|keyed_type| {
+            keyed_type
+                .value_type()
+                .map(|value_type| (keyed_type.key_type().clone(), value_type.clone()))
+        });
+        for (conf, receiver, output_type, method) in [
+            (
+                &field_conf.keys,
+                quote!(&self),
+                map_types.as_ref().map(|(key_type, _)| quote!(&#key_type)),
+                quote!(keys),
+            ),
+            (
+                &field_conf.values,
+                quote!(&self),
+                map_types
+                    .as_ref()
+                    .map(|(_, value_type)| quote!(&#value_type)),
+                quote!(values),
+            ),
+            (
+                &field_conf.values_mut,
+                quote!(&mut self),
+                map_types
+                    .as_ref()
+                    .map(|(_, value_type)| quote!(&mut #value_type)),
+                quote!(values_mut),
+            ),
+        ]
+        .iter()
+        {
+            if let Some(visibility) = conf.vis.to_ts() {
+                if let Some(output_type) = conf.explicit.check(
+                    output_type.as_ref(),
+                    &format!("the `{}` method is only supported for maps", method),
+                )? {
+                    let method_name = conf.name.complete(field_name)?;
+                    property.push(quote!(
+                        #visibility fn #method_name(#receiver) -> impl Iterator<Item = #output_type> + '_ {
+                            self.#field_name.#method()
+                        }
+                    ));
+                }
+            }
+        }
+    }
+    if let Some(visibility) = field_conf.size.vis.to_ts() {
+        let is_sized = match prop_field_type {
+            FieldType::String_
+            | FieldType::Array(_)
+            | FieldType::Vector(_)
+            | FieldType::VecDeque(_) => true,
+            _ => KeyedType::from_type(field_type).is_some(),
+        };
+        if field_conf
+            .size
+            .explicit
+            .check(
+                Some(()).filter(|_| is_sized),
+                "the `size` methods are only supported for `String`, arrays, `Vec`, `VecDeque`, maps and sets",
+            )?
+            .is_some()
+        {
+            let name = field_conf.size.name.complete(field_name)?;
+            let len_method_name = MethodNameConf::suffix("_len").complete(&name)?;
+            let is_empty_method_name = MethodNameConf::suffix("_is_empty").complete(&name)?;
+            property.push(quote!(
+                #visibility fn #len_method_name(&self) -> usize {
+                    self.#field_name.len()
+                }
+            ));
+            property.push(quote!(
+                #visibility fn #is_empty_method_name(&self) -> bool {
+                    self.#field_name.is_empty()
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.contains.vis.to_ts() {
+        let contains = match (
+            &prop_field_type,
+            prop_field_type.item_type(),
+            KeyedType::from_type(field_type),
+        ) {
+            (FieldType::String_, _, _) => Some(quote!(
+                (&self, pat: &str) -> bool {
+                    self.#field_name.contains(pat)
+                }
+            )),
+            (_, Some(item_type), _) => Some(quote!(
+                <Q>(&self, value: &Q) -> bool
+                where
+                    #item_type: ::core::borrow::Borrow<Q>,
+                    Q: ::core::cmp::PartialEq + ?Sized,
+                {
+                    self.#field_name
+                        .iter()
+                        .any(|item| ::core::borrow::Borrow::<Q>::borrow(item) == value)
+                }
+            )),
+            (_, _, Some(keyed_type)) => {
+                let key_type = keyed_type.key_type();
+                let bounds = keyed_type.lookup_bounds();
+                let (param, method) = if keyed_type.value_type().is_some() {
+                    (quote!(key), quote!(contains_key))
+                } else {
+                    (quote!(value), quote!(contains))
+                };
+                Some(quote!(
+                    <Q>(&self, #param: &Q) -> bool
+                    where
+                        #key_type: ::core::borrow::Borrow<Q>,
+                        Q: #bounds + ?Sized,
+                    {
+                        self.#field_name.#method(#param)
+                    }
+                ))
+            }
+            _ => None,
+        };
+        if let Some(contains) = field_conf.contains.explicit.check(
+            contains,
+            "the `contains` method is only supported for `String`, arrays, `Vec`, `VecDeque`, maps and sets",
+        )? {
+            let method_name = field_conf.contains.name.complete(field_name)?;
+            property.push(quote!(#visibility fn #method_name #contains));
+        }
+    }
+    if let Some(visibility) = field_conf.retain.vis.to_ts() {
+        let predicate = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::Vector(item_type), _) | (FieldType::VecDeque(item_type), _) => {
+                Some(quote!(impl FnMut(&#item_type) -> bool))
+            }
+            (_, Some(keyed_type)) => {
+                let key_type = keyed_type.key_type();
+                Some(if let Some(value_type) = keyed_type.value_type() {
+                    quote!(impl FnMut(&#key_type, &mut #value_type) -> bool)
+                } else {
+                    quote!(impl FnMut(&#key_type) -> bool)
+                })
+            }
+            _ => None,
+        };
+        if let Some(predicate) = field_conf.retain.explicit.check(
+            predicate,
+            "the `retain` method is only supported for `Vec`, `VecDeque`, maps and sets",
+        )? {
+            let method_name = field_conf.retain.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, f: #predicate) -> &mut Self {
+                    self.#field_name.retain(f);
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.truncate.vis.to_ts() {
+        let truncatable = match prop_field_type {
+            FieldType::String_ | FieldType::Vector(_) => Some(()),
+            _ => None,
+        };
+        if field_conf
+            .truncate
+            .explicit
+            .check(
+                truncatable,
+                "the `truncate` method is only supported for `String` and `Vec`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.truncate.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, len: usize) -> &mut Self {
+                    self.#field_name.truncate(len);
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.resize.vis.to_ts() {
+        let item_type = if let FieldType::Vector(ref item_type) = prop_field_type {
+            Some(item_type)
+        } else {
+            None
+        };
+        if let Some(item_type) = field_conf
+            .resize
+            .explicit
+            .check(item_type, "the `resize` method is only supported for `Vec`")?
+        {
+            let method_name = field_conf.resize.name.complete(field_name)?;
+            let assert_clone = assert_trait(item_type, quote!(::core::clone::Clone));
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, len: usize, value: #item_type) -> &mut Self {
+                    #assert_clone
+                    self.#field_name.resize(len, value);
+                    self
+                }
+            ));
+        }
+    }
+    for (conf, mutable, slice_method, deque_method) in [
+        (&field_conf.first, field_conf.first_mut, "first", "front"),
+        (&field_conf.last, field_conf.last_mut, "last", "back"),
+    ]
+    .iter()
+    {
+        let visibility = if let Some(visibility) = conf.vis.to_ts() {
+            visibility
+        } else {
+            continue;
+        };
+        let method = match prop_field_type {
+            FieldType::Array(_) | FieldType::Vector(_) => Some(slice_method),
+            FieldType::VecDeque(_) => Some(deque_method),
+            _ => None,
+        };
+        if let Some((method, item_type)) = conf.explicit.check(
+            method.zip(prop_field_type.item_type()),
+            &format!(
+                "the `{}` method is only supported for arrays, `Vec` and `VecDeque`",
+                slice_method
+            ),
+        )? {
+            let method_name = conf.name.complete(field_name)?;
+            let call = syn::Ident::new(method, proc_macro2::Span::call_site());
+            property.push(quote!(
+                #visibility fn #method_name(&self) -> Option<&#item_type> {
+                    self.#field_name.#call()
+                }
+            ));
+            if *mutable {
+                let method_name = MethodNameConf::suffix("_mut").complete(&method_name)?;
+                let call =
+                    syn::Ident::new(&format!("{}_mut", method), proc_macro2::Span::call_site());
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self) -> Option<&mut #item_type> {
+                        self.#field_name.#call()
+                    }
+                ));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf.entry.vis.to_ts() {
+        let keyed_type = KeyedType::from_type(field_type);
+        if let Some((key_type, entry_type)) = field_conf.entry.explicit.check(
+            keyed_type.as_ref().and_then(|keyed_type| {
+                keyed_type
+                    .entry_type()
+                    .map(|entry_type| (keyed_type.key_type(), entry_type))
+            }),
+            "the `entry` method is only supported for maps",
+        )? {
+            let method_name = field_conf.entry.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, key: impl Into<#key_type>) -> #entry_type {
+                    self.#field_name.entry(key.into())
+                }
+            ));
+        }
+    }
+    if field_conf.insert.vis.to_ts().is_some() || field_conf.remove.vis.to_ts().is_some() {
+        let keyed_type = KeyedType::from_type(field_type);
+        if let Some(visibility) = field_conf.insert.vis.to_ts() {
+            if let Some(keyed_type) = field_conf.insert.explicit.check(
+                keyed_type.as_ref(),
+                "the `insert` method is only supported for maps and sets",
+            )? {
+                let method_name = field_conf.insert.name.complete(field_name)?;
+                let key_type = keyed_type.key_type();
+                property.push(if let Some(value_type) = keyed_type.value_type() {
+                    quote!(
+                        #visibility fn #method_name(
+                            &mut self,
+                            key: impl Into<#key_type>,
+                            value: impl Into<#value_type>,
+                        ) -> Option<#value_type> {
+                            self.#field_name.insert(key.into(), value.into())
+                        }
+                    )
+                } else {
+                    quote!(
+                        #visibility fn #method_name(&mut self, value: impl Into<#key_type>) -> bool {
+                            self.#field_name.insert(value.into())
+                        }
+                    )
+                });
+            }
+        }
+        if let Some(visibility) = field_conf.remove.vis.to_ts() {
+            if let Some(keyed_type) = field_conf.remove.explicit.check(
+                keyed_type.as_ref(),
+                "the `remove` method is only supported for maps and sets",
+            )? {
+                let method_name = field_conf.remove.name.complete(field_name)?;
+                let key_type = keyed_type.key_type();
+                let bounds = keyed_type.lookup_bounds();
+                let output_type = if let Some(value_type) = keyed_type.value_type() {
+                    quote!(Option<#value_type>)
+                } else {
+                    quote!(bool)
+                };
+                property.push(quote!(
+                    #visibility fn #method_name<Q>(&mut self, key: &Q) -> #output_type
+                    where
+                        #key_type: ::core::borrow::Borrow<Q>,
+                        Q: #bounds + ?Sized,
+                    {
+                        self.#field_name.remove(key)
+                    }
+                ));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf.extend.vis.to_ts() {
+        let extend = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::String_, _) => Some((
+                quote!(<T: AsRef<str>>),
+                quote!(T),
+                quote!(for item in iter {
+                    self.#field_name.push_str(item.as_ref());
+                }),
+            )),
+            (FieldType::Vector(item_type), _) | (FieldType::VecDeque(item_type), _) => Some((
+                quote!(<T: Into<#item_type>>),
+                quote!(T),
+                quote!(self.#field_name.extend(iter.into_iter().map(Into::into));),
+            )),
+            (_, Some(keyed_type)) => {
+                let key_type = keyed_type.key_type();
+                Some(if let Some(value_type) = keyed_type.value_type() {
+                    (
+                        quote!(<K: Into<#key_type>, V: Into<#value_type>>),
+                        quote!((K, V)),
+                        quote!(self.#field_name.extend(
+                            iter.into_iter().map(|(key, value)| (key.into(), value.into()))
+                        );),
+                    )
+                } else {
+                    (
+                        quote!(<T: Into<#key_type>>),
+                        quote!(T),
+                        quote!(self.#field_name.extend(iter.into_iter().map(Into::into));),
+                    )
+                })
+            }
+            _ => None,
+        };
+        if let Some((generics, item_type, extend)) = field_conf.extend.explicit.check(
+            extend,
+            "the `extend` method is only supported for `String`, `Vec`, `VecDeque`, maps and sets",
+        )? {
+            let method_name = field_conf.extend.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name #generics(
+                    &mut self,
+                    iter: impl IntoIterator<Item = #item_type>,
+                ) -> &mut Self {
+                    #extend
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.append.vis.to_ts() {
+        let appendable = match prop_field_type {
+            FieldType::Vector(_) => Some(()),
+            _ => None,
+        };
+        if field_conf
+            .append
+            .explicit
+            .check(
+                appendable,
+                "the `append` method is only supported for `Vec`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.append.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, other: &mut #field_type) -> &mut Self {
+                    self.#field_name.append(other);
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.reserve.vis.to_ts() {
+        let reservable = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::String_, _)
+            | (FieldType::Vector(_), _)
+            | (FieldType::VecDeque(_), _)
+            | (_, Some(KeyedType::HashMap(..)))
+            | (_, Some(KeyedType::HashSet(_))) => Some(()),
+            _ => None,
+        };
+        if field_conf
+            .reserve
+            .explicit
+            .check(
+                reservable,
+                "the `reserve` method is only supported for `String`, `Vec`, `VecDeque`, `HashMap` and `HashSet`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.reserve.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, additional: usize) -> &mut Self {
+                    self.#field_name.reserve(additional);
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.drain.vis.to_ts() {
+        // The removed items are returned in a `Vec`, the items of maps are the key-value pairs.
+        // The `Vec` is resolved in the scope of the field, so it works in `no_std` crates too.
+        let drain = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::Vector(item_type), _) => Some((quote!(#item_type), Some(true))),
+            (FieldType::VecDeque(item_type), _) => Some((quote!(#item_type), Some(false))),
+            (_, Some(KeyedType::HashMap(key_type, value_type)))
+            | (_, Some(KeyedType::BTreeMap(key_type, value_type))) => {
+                Some((quote!((#key_type, #value_type)), None))
+            }
+            (_, Some(KeyedType::HashSet(item_type)))
+            | (_, Some(KeyedType::BTreeSet(item_type))) => Some((quote!(#item_type), None)),
+            _ => None,
+        };
+        if let Some((item_type, ranged)) = field_conf.drain.explicit.check(
+            drain,
+            "the `drain` method is only supported for `Vec`, `VecDeque`, maps and sets",
+        )? {
+            let method_name = field_conf.drain.name.complete(field_name)?;
+            property.push(if ranged == Some(false) {
+                quote!(
+                    #visibility fn #method_name(&mut self) -> Vec<#item_type> {
+                        self.#field_name.drain(..).collect()
+                    }
+                )
+            } else if ranged == Some(true) {
+                quote!(
+                    #visibility fn #method_name(&mut self) -> Vec<#item_type> {
+                        ::core::mem::take(&mut self.#field_name)
+                    }
+                )
+            } else {
+                // Not all maps and sets have `drain`, so take the whole field.
+                quote!(
+                    #visibility fn #method_name(&mut self) -> Vec<#item_type> {
+                        ::core::mem::take(&mut self.#field_name).into_iter().collect()
+                    }
+                )
+            });
+            if field_conf
+                .drain_range
+                .check(
+                    ranged,
+                    "the `range` of `drain` is only supported for `Vec` and `VecDeque`",
+                )?
+                .is_some()
+            {
+                let method_name = MethodNameConf::suffix("_range").complete(&method_name)?;
+                property.push(quote!(
+                    #visibility fn #method_name(
+                        &mut self,
+                        range: impl ::core::ops::RangeBounds<usize>,
+                    ) -> Vec<#item_type> {
+                        self.#field_name.drain(range).collect()
+                    }
+                ));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf.take.vis.to_ts() {
+        if field_conf
+            .take
+            .explicit
+            .check(
+                option_inner_type.as_ref(),
+                "the `take` method is only supported for `Option`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.take.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> #field_type {
+                    self.#field_name.take()
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.has.vis.to_ts() {
+        if field_conf
+            .has
+            .explicit
+            .check(
+                option_inner_type.as_ref(),
+                "the `has` method is only supported for `Option`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.has.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&self) -> bool {
+                    self.#field_name.is_some()
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.get_or_insert.vis.to_ts() {
+        if let Some(inner_type) = field_conf.get_or_insert.explicit.check(
+            option_inner_type.as_ref(),
+            "the `get_or_insert` method is only supported for `Option`",
+        )? {
+            let method_name = field_conf.get_or_insert.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, default: impl Into<#inner_type>) -> &mut #inner_type {
+                    self.#field_name.get_or_insert_with(|| default.into())
+                }
+            ));
+            if field_conf.get_or_insert_lazy {
+                let method_name = MethodNameConf::suffix("_with").complete(&method_name)?;
+                property.push(quote!(
+                    #visibility fn #method_name(
+                        &mut self,
+                        f: impl FnOnce() -> #inner_type,
+                    ) -> &mut #inner_type {
+                        self.#field_name.get_or_insert_with(f)
+                    }
+                ));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf.take_default.vis.to_ts() {
+        let method_name = field_conf.take_default.name.complete(field_name)?;
+        if option_inner_type.is_some() {
+            // Prefer the `take` method of `Option`, it's same as `mem::take`.
+            let take_method_name = if field_conf.take.vis.to_ts().is_some() {
+                Some(field_conf.take.name.complete(field_name)?)
+            } else {
+                None
+            };
+            if take_method_name.as_ref() != Some(&method_name) {
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self) -> #field_type {
+                        self.#field_name.take()
+                    }
+                ));
+            } else if let ExtraOptionConf::Enable(Some(span)) = field_conf.take_default.explicit {
+                return Err(SynError::new(
+                    span,
+                    format!(
+                        "the `take_default` method `{}` is same as the `take` method for `Option`, \
+                        disable one of them or set another name",
+                        method_name
+                    ),
+                ));
+            }
+        } else {
+            let assert_default = assert_trait(field_type, quote!(::core::default::Default));
+            let take = quote_spanned!(field_type.span()=> ::core::mem::take::<#field_type>);
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> #field_type {
+                    #assert_default
+                    #take(&mut self.#field_name)
+                }
+            ));
+        }
+    }
+    Ok(property)
+}
@@ -0,0 +1,683 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::{quote, quote_spanned};
+use syn::{ext::IdentExt, parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+
+use crate::{
+    generate::{traits::is_float_type, FieldType, GetType},
+    parse::{ContainerDef, ExtraOptionConf, FieldDef, SetConversionConf},
+};
+
+pub(crate) fn derive_builder(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let visibility = if let Some(visibility) = property.builder.to_ts() {
+        visibility
+    } else {
+        return Ok(None);
+    };
+    let name = &property.name;
+    if !property.generics.params.is_empty() {
+        return Err(SynError::new(
+            property.generics.span(),
+            "the builder is not supported for generic structs",
+        ));
+    }
+    let builder_name = syn::Ident::new(&format!("{}Builder", name.unraw()), name.span());
+    let mut fields = Vec::new();
+    let mut methods = Vec::new();
+    let mut stmts = Vec::new();
+    let mut values = Vec::new();
+    for f in property.fields.iter() {
+        let field_name = &f.ident;
+        let field_type = &f.ty;
+        values.push(quote!(#field_name));
+        let default_value = if let Some(ref expr) = f.conf.default_value {
+            quote!(#expr)
+        } else {
+            quote!(::core::default::Default::default())
+        };
+        // The fields which could not be set are initialized as the default values.
+        let setter_visibility = if let Some(visibility) = f.conf.set.vis.to_ts() {
+            visibility
+        } else {
+            stmts.push(quote!(let #field_name: #field_type = #default_value;));
+            continue;
+        };
+        if f.conf.skip {
+            stmts.push(quote!(let #field_name: #field_type = #default_value;));
+            continue;
+        }
+        fields.push(quote!(#field_name: Option<#field_type>));
+        let prop_field_type = FieldType::from_type(field_type);
+        methods.push(derive_optional_setter(f, &setter_visibility)?);
+        stmts.push(if f.conf.default || f.conf.default_value.is_some() {
+            quote!(let #field_name: #field_type = self.#field_name.unwrap_or_else(|| #default_value);)
+        } else if let FieldType::Option_(_) = prop_field_type {
+            quote!(let #field_name: #field_type = self.#field_name.unwrap_or(None);)
+        } else {
+            let msg = format!("the field `{}` is not set", field_name.unraw());
+            quote!(let #field_name: #field_type = self.#field_name.ok_or(#msg)?;)
+        });
+        stmts.push(derive_build_check(f)?);
+    }
+    let doc = format!("The builder of [`{}`].", name.unraw());
+    Ok(Some(quote!(
+        #[doc = #doc]
+        #[derive(Default)]
+        #visibility struct #builder_name {
+            #(#fields,)*
+        }
+
+        impl #builder_name {
+            #(#[inline] #methods)*
+
+            #[inline]
+            #visibility fn build(self) -> Result<#name, &'static str> {
+                #(#stmts)*
+                Ok(#name {
+                    #(#values,)*
+                })
+            }
+        }
+
+        impl #name {
+            #[inline]
+            #visibility fn builder() -> #builder_name {
+                Default::default()
+            }
+        }
+    )))
+}
+
+/// The checks of the `set` methods for the value of a field in the builder.
+///
+/// The error of the validator is replaced, since the error of the builder is a `&'static str`.
+fn derive_build_check(field: &FieldDef) -> ParseResult<proc_macro2::TokenStream> {
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let prop_field_type = FieldType::from_type(field_type);
+    let option_inner_type = prop_field_type.option_inner_type();
+    let is_option = option_inner_type.is_some() && !field.conf.set.full_option;
+    let limit = if let Some(ref range) = field.conf.set.range {
+        let inner_field_type = option_inner_type.as_ref().map(FieldType::from_type);
+        let number_type = match (&prop_field_type, inner_field_type) {
+            (FieldType::Number, _) => Some(field_type),
+            (FieldType::Option_(_), Some(FieldType::Number)) if is_option => {
+                option_inner_type.as_ref()
+            }
+            _ => None,
+        };
+        match range
+            .enable
+            .check(
+                number_type,
+                "the `min` and `max` are only supported for numbers",
+            )?
+            .map(|number_type| range.to_ts(number_type))
+        {
+            Some((min, max, true)) => {
+                let min = min.map(|min| quote!(if *val < #min { *val = #min; }));
+                let max = max.map(|max| quote!(if *val > #max { *val = #max; }));
+                quote!(#min #max)
+            }
+            Some((min, max, false)) => {
+                let min = min.map(|min| {
+                    let msg = format!(
+                        "the field `{}` is less than the minimum",
+                        field_name.unraw()
+                    );
+                    quote!(if *val < #min { return Err(#msg); })
+                });
+                let max = max.map(|max| {
+                    let msg = format!(
+                        "the field `{}` is greater than the maximum",
+                        field_name.unraw()
+                    );
+                    quote!(if *val > #max { return Err(#msg); })
+                });
+                quote!(#min #max)
+            }
+            None => quote!(),
+        }
+    } else {
+        quote!()
+    };
+    let validate = field.conf.set.validator.as_ref().map(|validator| {
+        let (func, _) = validator.to_ts();
+        let msg = format!("the field `{}` is invalid", field_name.unraw());
+        quote!(#func(val).map_err(|_| #msg)?;)
+    });
+    if limit.is_empty() && validate.is_none() {
+        return Ok(quote!());
+    }
+    // For `Option<T>` without `full_option`, the inner value is checked, same as the setters.
+    let ts = if is_option {
+        quote!(
+            let mut #field_name = #field_name;
+            if let Some(ref mut val) = #field_name {
+                #limit
+                #validate
+            }
+        )
+    } else {
+        quote!(
+            let mut #field_name = #field_name;
+            {
+                let val = &mut #field_name;
+                #limit
+                #validate
+            }
+        )
+    };
+    Ok(ts)
+}
+
+/// The setter of the structs which store the fields as `Option`, such as the builder.
+fn derive_optional_setter(
+    field: &FieldDef,
+    visibility: &proc_macro2::TokenStream,
+) -> ParseResult<proc_macro2::TokenStream> {
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let prop_field_type = FieldType::from_type(field_type);
+    let method_name = field.conf.set.name.complete(field_name)?;
+    let option_inner_type = prop_field_type.option_inner_type();
+    let is_direct = field.conf.set.conversion == SetConversionConf::Direct;
+    let strip_option_type = field.conf.set.strip_option.check(
+        option_inner_type.as_ref(),
+        "the `strip_option` is only supported for `Option`",
+    )?;
+    let (generics, input_type, value) = match prop_field_type {
+        _ if is_direct => match option_inner_type {
+            Some(ref inner_type) if !field.conf.set.full_option => {
+                (quote!(), quote!(#inner_type), quote!(Some(val)))
+            }
+            _ => (quote!(), quote!(#field_type), quote!(val)),
+        },
+        FieldType::Vector(ref item_type) => (
+            quote!(<T: Into<#item_type>>),
+            quote!(impl IntoIterator<Item = T>),
+            quote!(val.into_iter().map(Into::into).collect()),
+        ),
+        FieldType::Option_(_) if !field.conf.set.full_option => {
+            let inner_type = option_inner_type.as_ref().unwrap();
+            // Same as the `set` methods, `strip_option` takes the items for `Option<Vec<T>>`,
+            // and boxes the value for `Option<Box<T>>`.
+            match strip_option_type.map(|inner_type| {
+                (
+                    FieldType::from_type(inner_type),
+                    FieldType::sized_box_inner_type(inner_type),
+                )
+            }) {
+                Some((FieldType::Vector(item_type), _)) => (
+                    quote!(<T: Into<#item_type>>),
+                    quote!(impl IntoIterator<Item = T>),
+                    quote!(Some(val.into_iter().map(Into::into).collect())),
+                ),
+                Some((_, Some(box_inner_type))) => (
+                    quote!(<T: Into<#box_inner_type>>),
+                    quote!(T),
+                    quote!(Some(<#inner_type>::new(val.into()))),
+                ),
+                _ => (
+                    quote!(<T: Into<#inner_type>>),
+                    quote!(T),
+                    quote!(Some(val.into())),
+                ),
+            }
+        }
+        _ => (
+            quote!(<T: Into<#field_type>>),
+            quote!(T),
+            quote!(val.into()),
+        ),
+    };
+    Ok(quote!(
+        #visibility fn #method_name #generics(mut self, val: #input_type) -> Self {
+            self.#field_name = Some(#value);
+            self
+        }
+    ))
+}
+
+pub(crate) fn derive_patch(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let visibility = if let Some(visibility) = property.patch.vis.to_ts() {
+        visibility
+    } else {
+        return Ok(None);
+    };
+    let name = &property.name;
+    if !property.generics.params.is_empty() {
+        return Err(SynError::new(
+            property.generics.span(),
+            "the patch is not supported for generic structs",
+        ));
+    }
+    let patch_name = if let Some(ref patch_name) = property.patch.name {
+        patch_name.clone()
+    } else {
+        syn::Ident::new(&format!("{}Patch", name.unraw()), name.span())
+    };
+    let mut fields = Vec::new();
+    let mut methods = Vec::new();
+    let mut stmts = Vec::new();
+    for f in property.fields.iter().filter(|f| !f.conf.skip) {
+        let field_name = &f.ident;
+        let field_type = &f.ty;
+        fields.push(quote!(#field_name: Option<#field_type>));
+        methods.push(derive_optional_setter(f, &visibility)?);
+        stmts.push(quote!(
+            if let Some(val) = self.#field_name {
+                target.#field_name = val;
+            }
+        ));
+    }
+    // Avoid the warning of unused variables if all fields are skipped.
+    let target = if stmts.is_empty() {
+        quote!(_target)
+    } else {
+        quote!(target)
+    };
+    let doc = format!(
+        "The patch of [`{}`], only the fields which are set are applied.",
+        name.unraw()
+    );
+    Ok(Some(quote!(
+        #[doc = #doc]
+        #[derive(Default)]
+        #visibility struct #patch_name {
+            #(#fields,)*
+        }
+
+        impl #patch_name {
+            #(#[inline] #methods)*
+
+            #[inline]
+            #visibility fn apply_to(self, #target: &mut #name) {
+                #(#stmts)*
+            }
+        }
+    )))
+}
+
+pub(crate) fn derive_new(property: &ContainerDef) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let visibility = if let Some(visibility) = property.new.vis.to_ts() {
+        visibility
+    } else {
+        return Ok(None);
+    };
+    let method_name = &property.new.name;
+    let mut params = Vec::new();
+    let mut values = Vec::new();
+    for f in property.fields.iter() {
+        let field_name = &f.ident;
+        let field_type = &f.ty;
+        let prop_field_type = FieldType::from_type(field_type);
+        if property.new.optional_defaults {
+            if let FieldType::Option_(_) = prop_field_type {
+                values.push(quote!(#field_name: None));
+                continue;
+            }
+        }
+        if f.conf.skip || f.conf.new.skip || (property.new.optional_defaults && f.conf.default) {
+            if property.new.is_const {
+                return Err(SynError::new(
+                    field_name.span(),
+                    "the default value could not be used in a `const` constructor",
+                ));
+            }
+            values.push(quote!(#field_name: Default::default()));
+            continue;
+        }
+        if property.new.is_const {
+            params.push(quote!(#field_name: #field_type));
+            values.push(quote!(#field_name));
+            continue;
+        }
+        let (input_type, value) = match prop_field_type {
+            FieldType::Vector(ref item_type) => (
+                quote!(impl IntoIterator<Item = impl Into<#item_type>>),
+                quote!(#field_name.into_iter().map(Into::into).collect()),
+            ),
+            FieldType::Option_(_) if !f.conf.set.full_option => {
+                let inner_type = prop_field_type.option_inner_type();
+                (
+                    quote!(impl Into<#inner_type>),
+                    quote!(Some(#field_name.into())),
+                )
+            }
+            _ => (quote!(impl Into<#field_type>), quote!(#field_name.into())),
+        };
+        params.push(quote!(#field_name: #input_type));
+        values.push(quote!(#field_name: #value));
+    }
+    let constness = if property.new.is_const {
+        Some(quote!(const))
+    } else {
+        None
+    };
+    Ok(Some(quote!(
+        #visibility #constness fn #method_name(#(#params),*) -> Self {
+            Self {
+                #(#values,)*
+            }
+        }
+    )))
+}
+
+pub(crate) fn derive_reset_all(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    let visibility = property.reset_all.vis.to_ts()?;
+    let method_name = &property.reset_all.name;
+    let stmts = property
+        .fields
+        .iter()
+        .filter(|f| !f.conf.skip && !f.conf.reset_all.skip)
+        .map(|f| {
+            let field_name = &f.ident;
+            let field_type = &f.ty;
+            // The trait bound is checked by the call, the error will point to the field type.
+            let default = quote_spanned!(field_type.span()=>
+                <#field_type as ::core::default::Default>::default()
+            );
+            quote!(self.#field_name = #default;)
+        });
+    Some(quote!(
+        #visibility fn #method_name(&mut self) -> &mut Self {
+            #(#stmts)*
+            self
+        }
+    ))
+}
+
+pub(crate) fn derive_fields_mut(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    let visibility = property.fields_mut.vis.to_ts()?;
+    let method_name = &property.fields_mut.name;
+    let fields = property
+        .fields
+        .iter()
+        .filter(|f| !f.conf.skip)
+        .collect::<Vec<_>>();
+    let field_names = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|f| &f.ty);
+    // Borrow all fields by one destructuring, so the borrows are split.
+    Some(quote!(
+        #visibility fn #method_name(&mut self) -> (#(&mut #field_types,)*) {
+            let Self { #(#field_names,)* .. } = self;
+            (#(#field_names,)*)
+        }
+    ))
+}
+
+/// Generate a trait which contains the methods for fields, and implement it for the container.
+pub(crate) fn derive_trait(
+    property: &ContainerDef,
+    methods: &[proc_macro2::TokenStream],
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let visibility = if let Some(visibility) = property.trait_.vis.to_ts() {
+        visibility
+    } else {
+        return Ok(None);
+    };
+    let name = &property.name;
+    if !property.generics.params.is_empty() {
+        return Err(SynError::new(
+            property.generics.span(),
+            "the `trait` is not supported for generic structs",
+        ));
+    }
+    let trait_name = property
+        .trait_
+        .name
+        .as_ref()
+        .expect("the trait name is required");
+    let mut trait_items = Vec::new();
+    let mut impl_items = Vec::new();
+    for method in methods {
+        let mut method = syn::parse2::<syn::ImplItemMethod>(method.to_owned())?;
+        method.vis = syn::Visibility::Inherited;
+        method.sig.constness = None;
+        // The methods which are not allowed for trait objects require `Self: Sized`.
+        let sig = &method.sig;
+        let is_generic = sig.generics.type_params().next().is_some()
+            || sig.generics.const_params().next().is_some();
+        let takes_self = if let Some(syn::FnArg::Receiver(receiver)) = sig.receiver() {
+            receiver.reference.is_none()
+        } else {
+            false
+        };
+        let output = &sig.output;
+        let has_impl_trait = sig.inputs.iter().any(|arg| has_ident(quote!(#arg), "impl"))
+            || has_ident(quote!(#output), "impl");
+        let returns_self = has_ident(quote!(#output), "Self");
+        if is_generic || takes_self || has_impl_trait || returns_self {
+            method
+                .sig
+                .generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(Self: Sized));
+        }
+        // The patterns are not allowed in the declarations.
+        let mut sig = method.sig.clone();
+        for arg in sig.inputs.iter_mut() {
+            match arg {
+                syn::FnArg::Receiver(receiver) => {
+                    if receiver.reference.is_none() {
+                        receiver.mutability = None;
+                    }
+                }
+                syn::FnArg::Typed(pat_type) => {
+                    if let syn::Pat::Ident(ref mut pat_ident) = *pat_type.pat {
+                        pat_ident.mutability = None;
+                    }
+                }
+            }
+        }
+        trait_items.push(quote!(#sig;));
+        impl_items.push(method);
+    }
+    Ok(Some(quote!(
+        #visibility trait #trait_name {
+            #(#trait_items)*
+        }
+
+        impl #trait_name for #name {
+            #(#[inline] #impl_items)*
+        }
+    )))
+}
+
+fn has_ident(ts: proc_macro2::TokenStream, name: &str) -> bool {
+    ts.into_iter().any(|tt| match tt {
+        proc_macro2::TokenTree::Ident(ident) => ident == name,
+        proc_macro2::TokenTree::Group(group) => has_ident(group.stream(), name),
+        _ => false,
+    })
+}
+
+pub(crate) fn derive_fields_const(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    let visibility = property.fields_const.vis.to_ts()?;
+    let const_name = &property.fields_const.name;
+    let field_names = property.fields.iter().filter(|f| !f.conf.skip).map(|f| {
+        f.conf
+            .reflect_name
+            .clone()
+            .unwrap_or_else(|| f.ident.unraw().to_string())
+    });
+    Some(quote!(
+        #visibility const #const_name: &'static [&'static str] = &[#(#field_names,)*];
+    ))
+}
+
+pub(crate) fn derive_field_names(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    if !property.fields_const.method {
+        return None;
+    }
+    let visibility = property.fields_const.vis.to_ts()?;
+    let const_name = &property.fields_const.name;
+    Some(quote!(
+        #visibility fn field_names() -> &'static [&'static str] {
+            Self::#const_name
+        }
+    ))
+}
+
+pub(crate) fn derive_parts(property: &ContainerDef) -> Option<Vec<proc_macro2::TokenStream>> {
+    let visibility = property.parts.vis.to_ts()?;
+    let (fields, skipped): (Vec<_>, Vec<_>) = property.fields.iter().partition(|f| !f.conf.skip);
+    let field_names = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let field_types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let defaults = skipped.iter().map(|f| {
+        let field_name = &f.ident;
+        let field_type = &f.ty;
+        quote_spanned!(field_type.span()=> #field_name: <#field_type as ::core::default::Default>::default())
+    });
+    // The order of the fields is a part of the API, so it's listed in the docs.
+    let order = field_names
+        .iter()
+        .map(|name| format!("`{}`", name.unraw()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let into_doc = format!("Returns the fields as a tuple in the order: {}.", order);
+    let from_doc = format!(
+        "Creates an instance from a tuple of the fields in the order: {}.\n\n\
+         The skipped fields are set to the default values.",
+        order
+    );
+    Some(vec![
+        quote!(
+            #[doc = #into_doc]
+            #visibility fn into_parts(self) -> (#(#field_types,)*) {
+                (#(self.#field_names,)*)
+            }
+        ),
+        quote!(
+            #[doc = #from_doc]
+            #visibility fn from_parts(parts: (#(#field_types,)*)) -> Self {
+                let (#(#field_names,)*) = parts;
+                Self {
+                    #(#field_names,)*
+                    #(#defaults,)*
+                }
+            }
+        ),
+    ])
+}
+
+pub(crate) fn derive_sort_key(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let visibility = if let Some(visibility) = property.sort_key.vis.to_ts() {
+        visibility
+    } else {
+        return Ok(None);
+    };
+    let method_name = &property.sort_key.name;
+    let mut ordered = property
+        .fields
+        .iter()
+        .filter(|f| f.conf.ord.number.is_some())
+        .collect::<Vec<_>>();
+    if ordered.is_empty() {
+        return Err(SynError::new(
+            method_name.span(),
+            "the `sort_key` requires at least one field which has the `ord` property",
+        ));
+    }
+    ordered.sort_by_key(|f| f.conf.ord.number);
+    let mut key_types = Vec::new();
+    let mut keys = Vec::new();
+    for f in ordered {
+        let field_name = &f.ident;
+        let field_type = &f.ty;
+        let ord = &f.conf.ord;
+        let is_total = if let ExtraOptionConf::Enable(_) = ord.total {
+            is_float_type(field_type)
+        } else {
+            false
+        };
+        if ord.by.is_some() || ord.none_order.is_some() || is_total {
+            return Err(SynError::new(
+                field_name.span(),
+                "the `sort_key` doesn't support the fields with `by`, `none_first`, `none_last` \
+                 or `total` of `ord`",
+            ));
+        }
+        // The fields are borrowed as the getters do.
+        let (key_type, key) = if let ExtraOptionConf::Enable(_) = ord.by_len {
+            (quote!(usize), quote!(self.#field_name.len()))
+        } else {
+            match GetType::from_field_type(&FieldType::from_type(field_type)) {
+                GetType::String_ => (quote!(&str), quote!(&self.#field_name)),
+                GetType::Slice(type_slice) => (quote!(&#type_slice), quote!(&self.#field_name)),
+                GetType::Option_(inner_type) => (
+                    quote!(::core::option::Option<&#inner_type>),
+                    quote!(self.#field_name.as_ref()),
+                ),
+                _ => (quote!(&#field_type), quote!(&self.#field_name)),
+            }
+        };
+        if ord.sort_type.is_ascending() {
+            key_types.push(key_type);
+            keys.push(key);
+        } else {
+            key_types.push(quote!(::core::cmp::Reverse<#key_type>));
+            keys.push(quote!(::core::cmp::Reverse(#key)));
+        }
+    }
+    Ok(Some(quote!(
+        #visibility fn #method_name(&self) -> (#(#key_types,)*) {
+            (#(#keys,)*)
+        }
+    )))
+}
+
+pub(crate) fn derive_merge(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    let visibility = property.merge.vis.to_ts()?;
+    let method_name = property.merge.name.clone().unwrap_or_else(|| {
+        let default_name = if property.merge.options {
+            "or_merge"
+        } else {
+            "merge_from"
+        };
+        syn::Ident::new(default_name, proc_macro2::Span::call_site())
+    });
+    let stmts = property
+        .fields
+        .iter()
+        .filter(|f| !f.conf.skip && !f.conf.merge.skip)
+        .filter_map(|f| {
+            let field_name = &f.ident;
+            match FieldType::from_type(&f.ty) {
+                FieldType::Option_(_) if property.merge.options => Some(quote!(
+                    if self.#field_name.is_none() {
+                        self.#field_name = other.#field_name;
+                    }
+                )),
+                _ if property.merge.options && !property.merge.overwrite => None,
+                _ => Some(quote!(self.#field_name = other.#field_name;)),
+            }
+        })
+        .collect::<Vec<_>>();
+    // Avoid the warning of unused variables if all fields are ignored.
+    let other = if stmts.is_empty() {
+        quote!(_other)
+    } else {
+        quote!(other)
+    };
+    Some(quote!(
+        #visibility fn #method_name(&mut self, #other: Self) -> &mut Self {
+            #(#stmts)*
+            self
+        }
+    ))
+}
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::{quote, quote_spanned};
+use syn::{ext::IdentExt, parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+
+use crate::{
+    generate::{assert_trait, FieldType, GetType},
+    parse::{FieldDef, GetTypeConf, MethodNameConf},
+};
+
+/// The getters of a field.
+pub(crate) fn derive_getters(field: &FieldDef) -> ParseResult<Vec<proc_macro2::TokenStream>> {
+    let mut property = Vec::new();
+    let field_type = &field.ty;
+    let field_name = &field.ident;
+    let field_conf = &field.conf;
+    let prop_field_type = FieldType::from_type(field_type);
+    let option_inner_type = prop_field_type.option_inner_type();
+    let raw_option = field_conf
+        .get
+        .raw_option
+        .check(
+            option_inner_type.as_ref(),
+            "the raw option getter is only supported for `Option`",
+        )?
+        .is_some();
+    // Check if the get type is supported, when it's not overridden by other properties.
+    let is_get_type_applied =
+        field_conf.get.vis.to_ts().is_some() && field_conf.get.with.is_none() && !raw_option;
+    if is_get_type_applied {
+        let err_msg_opt = match field_conf.get.typ {
+            GetTypeConf::TryRef if option_inner_type.is_none() => {
+                Some("the `try_ref` getter is only supported for `Option`")
+            }
+            GetTypeConf::Bytes => match prop_field_type {
+                FieldType::String_ => None,
+                _ if prop_field_type.is_bytes() => None,
+                _ => Some("the `bytes` getter is only supported for `String` and `Vec<u8>`"),
+            },
+            GetTypeConf::Copy_ if prop_field_type.is_not_copy() => {
+                Some("the `copy` getter requires a `Copy` type, try `clone` instead")
+            }
+            _ => None,
+        };
+        if let Some(err_msg) = err_msg_opt {
+            return Err(SynError::new(field_type.span(), err_msg));
+        }
+    }
+    let method_name = field_conf.get.name.complete(field_name)?;
+    if let Some(ts) = field_conf.get.vis.to_ts().map(|visibility| {
+        let get_type = if let Some(ref with) = field_conf.get.with {
+            let (func, returns) = with.to_ts();
+            GetType::Custom(func, Box::new(returns))
+        } else {
+            match field_conf.get.typ {
+                _ if raw_option => GetType::Ref,
+                GetTypeConf::Auto => match GetType::from_field_type(&prop_field_type) {
+                    GetType::String_ | GetType::Slice(_) if field_conf.get.no_coerce => {
+                        GetType::Ref
+                    }
+                    get_type => get_type,
+                },
+                GetTypeConf::Ref => GetType::Ref,
+                GetTypeConf::Copy_ => GetType::Copy_,
+                GetTypeConf::Clone_ => GetType::Clone_,
+                GetTypeConf::TryRef => GetType::TryRef,
+                GetTypeConf::Bytes => GetType::Bytes,
+            }
+        };
+        match get_type {
+            GetType::Ref => quote!(
+                #visibility fn #method_name(&self) -> &#field_type {
+                    &self.#field_name
+                }
+            ),
+            GetType::Copy_ => {
+                // Only check the types which are not detected automatically.
+                let assertion = if let GetTypeConf::Copy_ = field_conf.get.typ {
+                    assert_trait(field_type, quote!(::core::marker::Copy))
+                } else {
+                    quote!()
+                };
+                quote!(
+                    #visibility fn #method_name(&self) -> #field_type {
+                        #assertion
+                        self.#field_name
+                    }
+                )
+            }
+            GetType::Clone_ => {
+                // The trait bound is checked by the call, the error will point to the field type.
+                let value = quote_spanned!(field_type.span()=>
+                    ::core::clone::Clone::clone(&self.#field_name)
+                );
+                quote!(
+                    #visibility fn #method_name(&self) -> #field_type {
+                        #value
+                    }
+                )
+            }
+            GetType::String_ => quote!(
+                #visibility fn #method_name(&self) -> &str {
+                    &self.#field_name[..]
+                }
+            ),
+            GetType::Slice(field_type) => quote!(
+                #visibility fn #method_name(&self) -> &#field_type {
+                    &self.#field_name[..]
+                }
+            ),
+            GetType::Option_(field_type) => quote!(
+                #visibility fn #method_name(&self) -> Option<&#field_type> {
+                    self.#field_name.as_ref()
+                }
+            ),
+            GetType::TryRef => {
+                let inner_type = option_inner_type
+                    .as_ref()
+                    .expect("the type has been checked");
+                let (inner_ref_type, inner_ref) = match FieldType::from_type(inner_type) {
+                    FieldType::String_ => (quote!(&str), quote!(as_deref)),
+                    FieldType::Vector(item_type) => (quote!(&[#item_type]), quote!(as_deref)),
+                    _ => (quote!(&#inner_type), quote!(as_ref)),
+                };
+                let field_name_str = field_name.unraw().to_string();
+                let (error_type, error) = if let Some(ref error) = field_conf.get.error {
+                    let error: syn::Path =
+                        syn::parse_str(error).expect("the path has been checked");
+                    (quote!(#error), quote!(#error(#field_name_str)))
+                } else {
+                    (quote!(&'static str), quote!(#field_name_str))
+                };
+                quote!(
+                    #visibility fn #method_name(&self) -> Result<#inner_ref_type, #error_type> {
+                        match self.#field_name.#inner_ref() {
+                            Some(value) => Ok(value),
+                            None => Err(#error),
+                        }
+                    }
+                )
+            }
+            GetType::Bytes => {
+                let value = if let FieldType::String_ = prop_field_type {
+                    quote!(self.#field_name.as_bytes())
+                } else {
+                    quote!(&self.#field_name[..])
+                };
+                quote!(
+                    #visibility fn #method_name(&self) -> &[u8] {
+                        #value
+                    }
+                )
+            }
+            GetType::Custom(func, returns) => quote!(
+                #visibility fn #method_name(&self) -> #returns {
+                    #func(&self.#field_name)
+                }
+            ),
+        }
+    }) {
+        let attrs = field_conf.get.attrs.to_ts();
+        property.push(quote!(#attrs #ts));
+    }
+    // The options are checked even if the getter is disabled.
+    let indexed_item_type = field_conf.get.indexed.check(
+        prop_field_type.item_type(),
+        "the indexed getter is only supported for arrays, `Vec` and `VecDeque`",
+    )?;
+    if let Some(visibility) = field_conf.get.vis.to_ts() {
+        if let Some(item_type) = indexed_item_type {
+            let method_name = MethodNameConf::suffix("_at")
+                .complete(&field_conf.get.name.complete(field_name)?)?;
+            property.push(quote!(
+                #visibility fn #method_name(&self, index: usize) -> Option<&#item_type> {
+                    self.#field_name.get(index)
+                }
+            ));
+        }
+    }
+    let slice_item_type = field_conf.get.slice_range.check(
+        prop_field_type.slice_item_type(),
+        "the range slice getter is only supported for arrays and `Vec`",
+    )?;
+    if let Some(visibility) = field_conf.get.vis.to_ts() {
+        if let Some(item_type) = slice_item_type {
+            let method_name = MethodNameConf::suffix("_slice")
+                .complete(&field_conf.get.name.complete(field_name)?)?;
+            property.push(quote!(
+                #visibility fn #method_name(
+                    &self,
+                    range: impl ::core::ops::RangeBounds<usize>,
+                ) -> Option<&[#item_type]> {
+                    let start = match range.start_bound() {
+                        ::core::ops::Bound::Included(&n) => n,
+                        ::core::ops::Bound::Excluded(&n) => n.checked_add(1)?,
+                        ::core::ops::Bound::Unbounded => 0,
+                    };
+                    let end = match range.end_bound() {
+                        ::core::ops::Bound::Included(&n) => n.checked_add(1)?,
+                        ::core::ops::Bound::Excluded(&n) => n,
+                        ::core::ops::Bound::Unbounded => self.#field_name.len(),
+                    };
+                    self.#field_name.get(start..end)
+                }
+            ));
+        }
+    }
+    Ok(property)
+}
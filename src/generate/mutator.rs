@@ -0,0 +1,370 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::{quote, quote_spanned};
+use syn::{parse::Result as ParseResult, spanned::Spanned};
+
+use crate::{
+    generate::{ClrMethod, FieldType, KeyedType},
+    parse::{ClrScopeConf, ExtraOptionConf, FieldDef, MethodNameConf},
+};
+
+/// The methods which change a field in place or move it out.
+pub(crate) fn derive_mutators(field: &FieldDef) -> ParseResult<Vec<proc_macro2::TokenStream>> {
+    let mut property = Vec::new();
+    let field_type = &field.ty;
+    let field_name = &field.ident;
+    let field_conf = &field.conf;
+    let prop_field_type = FieldType::from_type(field_type);
+    let option_inner_type = prop_field_type.option_inner_type();
+    let mut_slice_type = if field_conf.mut_.vis.to_ts().is_some() {
+        let slice_type = match prop_field_type {
+            FieldType::String_ => Some(quote!(str)),
+            _ => prop_field_type
+                .slice_item_type()
+                .map(|item_type| quote!([#item_type])),
+        };
+        field_conf.mut_.slice.check(
+            slice_type,
+            "the `slice` type of `mut` is only supported for arrays, `Vec` and `String`",
+        )?
+    } else {
+        None
+    };
+    let mut_inner_type = if field_conf.mut_.vis.to_ts().is_some() {
+        field_conf.mut_.inner.check(
+            option_inner_type.as_ref(),
+            "the `inner` type of `mut` is only supported for `Option`",
+        )?
+    } else {
+        None
+    };
+    let method_name = field_conf.mut_.name.complete(field_name)?;
+    if let Some(ts) = field_conf.mut_.vis.to_ts().map(|visibility| {
+        if let Some(slice_type) = mut_slice_type {
+            // The items could be changed, but not the length.
+            return quote!(
+                #visibility fn #method_name(&mut self) -> &mut #slice_type {
+                    &mut self.#field_name[..]
+                }
+            );
+        }
+        match (&option_inner_type, FieldType::box_inner_type(field_type)) {
+            (Some(ref inner_type), _) if !field_conf.mut_.raw || mut_inner_type.is_some() => {
+                quote!(
+                    #visibility fn #method_name(&mut self) -> Option<&mut #inner_type> {
+                        self.#field_name.as_mut()
+                    }
+                )
+            }
+            (_, Some(ref inner_type)) if !field_conf.mut_.raw => quote!(
+                #visibility fn #method_name(&mut self) -> &mut #inner_type {
+                    &mut *self.#field_name
+                }
+            ),
+            _ => match FieldType::cow_owned_type(field_type) {
+                // The borrowed data is cloned when it's changed at the first time.
+                Some(owned_type) if !field_conf.mut_.raw => quote!(
+                    #visibility fn #method_name(&mut self) -> &mut #owned_type {
+                        self.#field_name.to_mut()
+                    }
+                ),
+                _ => quote!(
+                    #visibility fn #method_name(&mut self) -> &mut #field_type {
+                        &mut self.#field_name
+                    }
+                ),
+            },
+        }
+    }) {
+        let attrs = field_conf.mut_.attrs.to_ts();
+        property.push(quote!(#attrs #ts));
+    }
+    if let Some(visibility) = field_conf.mut_.vis.to_ts() {
+        if let Some(inner_type) = field_conf.mut_.or_default.check(
+            option_inner_type.as_ref(),
+            "the `or_default` of `mut` is only supported for `Option`",
+        )? {
+            let method_name = MethodNameConf::suffix("_or_default")
+                .complete(&field_conf.mut_.name.complete(field_name)?)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> &mut #inner_type {
+                    self.#field_name.get_or_insert_with(::core::default::Default::default)
+                }
+            ));
+        }
+        if let Some(item_type) = field_conf.mut_.indexed.check(
+            prop_field_type.item_type(),
+            "the indexed mutable accessor is only supported for arrays, `Vec` and `VecDeque`",
+        )? {
+            let method_name = MethodNameConf::suffix("_at")
+                .complete(&field_conf.mut_.name.complete(field_name)?)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, index: usize) -> Option<&mut #item_type> {
+                    self.#field_name.get_mut(index)
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.clr.vis.to_ts() {
+        let method_name = field_conf.clr.name.complete(field_name)?;
+        let auto_clr_method = ClrMethod::from_field_type(&prop_field_type);
+        let clr_method = match field_conf.clr.scope {
+            ClrScopeConf::Auto => auto_clr_method,
+            ClrScopeConf::Option_ => {
+                if auto_clr_method == ClrMethod::SetNone {
+                    auto_clr_method
+                } else {
+                    ClrMethod::None_
+                }
+            }
+            ClrScopeConf::All => {
+                if auto_clr_method == ClrMethod::None_ {
+                    ClrMethod::SetDefault
+                } else {
+                    auto_clr_method
+                }
+            }
+        };
+        let clear = match clr_method {
+            ClrMethod::SetZero => Some(quote!(self.#field_name = 0;)),
+            ClrMethod::SetNone => Some(quote!(self.#field_name = None;)),
+            ClrMethod::SetDefault => Some(quote!(self.#field_name = Default::default();)),
+            ClrMethod::CallClear => Some(quote!(self.#field_name.clear();)),
+            ClrMethod::FillWithDefault => {
+                Some(quote!(self.#field_name.fill_with(Default::default);))
+            }
+            ClrMethod::None_ => field_conf.clr.explicit.check(
+                None,
+                "the `clr` method is not supported for this type in the scope",
+            )?,
+        };
+        if let Some(clear) = clear {
+            if let ExtraOptionConf::Disable = field_conf.clr.chain {
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self) {
+                        #clear
+                    }
+                ));
+            } else {
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self) -> &mut Self {
+                        #clear
+                        self
+                    }
+                ));
+            }
+        }
+    }
+    let method_name = field_conf.into.name.complete(field_name)?;
+    if let Some(ts) = field_conf.into.vis.to_ts().map(|visibility| {
+        quote!(
+            #visibility fn #method_name(self) -> #field_type {
+                self.#field_name
+            }
+        )
+    }) {
+        property.push(ts);
+    }
+    if let Some(visibility) = field_conf.into_iter.vis.to_ts() {
+        let item_type = match (&prop_field_type, KeyedType::from_type(field_type)) {
+            (FieldType::Vector(item_type), _) | (FieldType::VecDeque(item_type), _) => {
+                Some(quote!(#item_type))
+            }
+            (_, Some(keyed_type)) => {
+                let key_type = keyed_type.key_type();
+                Some(if let Some(value_type) = keyed_type.value_type() {
+                    quote!((#key_type, #value_type))
+                } else {
+                    quote!(#key_type)
+                })
+            }
+            _ => None,
+        };
+        if let Some(item_type) = field_conf.into_iter.explicit.check(
+            item_type,
+            "the `into_iter` method is only supported for `Vec`, `VecDeque`, maps and sets",
+        )? {
+            let method_name = field_conf.into_iter.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(self) -> impl Iterator<Item = #item_type> {
+                    self.#field_name.into_iter()
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.update.vis.to_ts() {
+        let method_name = field_conf.update.name.complete(field_name)?;
+        property.push(quote!(
+            #visibility fn #method_name<R>(&mut self, f: impl FnOnce(&mut #field_type) -> R) -> R {
+                f(&mut self.#field_name)
+            }
+        ));
+    }
+    if let Some(visibility) = field_conf.with_mut.vis.to_ts() {
+        let method_name = field_conf.with_mut.name.complete(field_name)?;
+        property.push(
+            if let Some(inner_type) = field_conf.with_mut_flatten.check(
+                option_inner_type.as_ref(),
+                "the `flatten` of `with_mut` is only supported for `Option`",
+            )? {
+                quote!(
+                    #visibility fn #method_name<R>(
+                        &mut self,
+                        f: impl FnOnce(&mut #inner_type) -> R,
+                    ) -> Option<R> {
+                        self.#field_name.as_mut().map(f)
+                    }
+                )
+            } else {
+                quote!(
+                    #visibility fn #method_name<R>(
+                        &mut self,
+                        f: impl FnOnce(&mut #field_type) -> R,
+                    ) -> R {
+                        f(&mut self.#field_name)
+                    }
+                )
+            },
+        );
+    }
+    if let Some(visibility) = field_conf.swap.vis.to_ts() {
+        let method_name = field_conf.swap.name.complete(field_name)?;
+        property.push(quote!(
+            #visibility fn #method_name(&mut self, other: &mut Self) {
+                ::core::mem::swap(&mut self.#field_name, &mut other.#field_name);
+            }
+        ));
+    }
+    if let Some(visibility) = field_conf.reset.vis.to_ts() {
+        let method_name = field_conf.reset.name.complete(field_name)?;
+        // The trait bound is checked by the call, the error will point to the field type.
+        let default = quote_spanned!(field_type.span()=>
+            <#field_type as ::core::default::Default>::default()
+        );
+        property.push(quote!(
+            #visibility fn #method_name(&mut self) -> &mut Self {
+                self.#field_name = #default;
+                self
+            }
+        ));
+    }
+    if let Some(visibility) = field_conf.map.vis.to_ts() {
+        let method_name = field_conf.map.name.complete(field_name)?;
+        // The field is moved out, so the error for the structs which implement `Drop` is spanned
+        // at the field.
+        let map = quote_spanned!(field_name.span()=> self.#field_name = f(self.#field_name););
+        property.push(quote!(
+            #visibility fn #method_name(
+                mut self,
+                f: impl FnOnce(#field_type) -> #field_type,
+            ) -> Self {
+                #map
+                self
+            }
+        ));
+    }
+    if let Some(visibility) = field_conf.toggle.vis.to_ts() {
+        let is_boolean = if let FieldType::Boolean = prop_field_type {
+            Some(())
+        } else {
+            None
+        };
+        if field_conf
+            .toggle
+            .explicit
+            .check(
+                is_boolean,
+                "the `toggle` method is only supported for `bool`",
+            )?
+            .is_some()
+        {
+            let method_name = field_conf.toggle.name.complete(field_name)?;
+            property.push(quote!(
+                #visibility fn #method_name(&mut self) -> bool {
+                    self.#field_name = !self.#field_name;
+                    self.#field_name
+                }
+            ));
+        }
+    }
+    for (conf, op, msg) in &[
+        (
+            &field_conf.add,
+            quote!(+=),
+            "the `add` method is only supported for numbers",
+        ),
+        (
+            &field_conf.sub,
+            quote!(-=),
+            "the `sub` method is only supported for numbers",
+        ),
+    ] {
+        if let Some(visibility) = conf.vis.to_ts() {
+            let is_number = if let FieldType::Number = prop_field_type {
+                Some(())
+            } else {
+                None
+            };
+            if conf.explicit.check(is_number, msg)?.is_some() {
+                let method_name = conf.name.complete(field_name)?;
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self, delta: #field_type) -> &mut Self {
+                        self.#field_name #op delta;
+                        self
+                    }
+                ));
+            }
+        }
+    }
+    if field_conf.checked_add.vis.to_ts().is_some()
+        || field_conf.saturating_add.vis.to_ts().is_some()
+    {
+        let is_integer = Some(()).filter(|_| FieldType::is_integer(field_type));
+        if let Some(visibility) = field_conf.checked_add.vis.to_ts() {
+            if field_conf
+                .checked_add
+                .explicit
+                .check(
+                    is_integer,
+                    "the `checked_add` method is only supported for integers",
+                )?
+                .is_some()
+            {
+                let method_name = field_conf.checked_add.name.complete(field_name)?;
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self, delta: #field_type) -> Option<#field_type> {
+                        let value = self.#field_name.checked_add(delta)?;
+                        self.#field_name = value;
+                        Some(value)
+                    }
+                ));
+            }
+        }
+        if let Some(visibility) = field_conf.saturating_add.vis.to_ts() {
+            if field_conf
+                .saturating_add
+                .explicit
+                .check(
+                    is_integer,
+                    "the `saturating_add` method is only supported for integers",
+                )?
+                .is_some()
+            {
+                let method_name = field_conf.saturating_add.name.complete(field_name)?;
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self, delta: #field_type) -> &mut Self {
+                        self.#field_name = self.#field_name.saturating_add(delta);
+                        self
+                    }
+                ));
+            }
+        }
+    }
+    Ok(property)
+}
@@ -0,0 +1,1041 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use quote::{quote, quote_spanned};
+use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+
+use crate::{
+    generate::{assert_trait, FieldType, KeyedType, LockType},
+    parse::{
+        FieldDef, MethodAttrsConf, MethodNameConf, SetConversionConf, SetTypeConf, SetValidatorConf,
+    },
+};
+
+/// The setters of a field, include the `with` method and the companion setters.
+pub(crate) fn derive_setters(field: &FieldDef) -> ParseResult<Vec<proc_macro2::TokenStream>> {
+    let mut property = Vec::new();
+    let field_type = &field.ty;
+    let field_name = &field.ident;
+    let field_conf = &field.conf;
+    let prop_field_type = FieldType::from_type(field_type);
+    let option_inner_type = prop_field_type.option_inner_type();
+    let is_set_enabled =
+        field_conf.set.vis.to_ts().is_some() || field_conf.with.vis.to_ts().is_some();
+    let set_strip_option = if is_set_enabled {
+        field_conf.set.strip_option.check(
+            option_inner_type.as_ref(),
+            "the `strip_option` is only supported for `Option`",
+        )?
+    } else {
+        None
+    };
+    let set_vec_item_type = set_strip_option.and_then(|inner_type| {
+        if let FieldType::Vector(item_type) = FieldType::from_type(inner_type) {
+            Some(item_type)
+        } else {
+            None
+        }
+    });
+    // The value is wrapped by the setter, it's boxed for `Option<Box<T>>` with `strip_option`,
+    // or shared for `Arc<T>` and `Rc<T>` with `wrap`, where `T` is sized; the unsized ones are
+    // converted into the `Box` directly.
+    let set_box_inner_type = set_strip_option.and_then(FieldType::sized_box_inner_type);
+    let set_shared_inner_type = if is_set_enabled {
+        field_conf.set.wrap.check(
+            FieldType::shared_inner_type(field_type),
+            "the `wrap` is only supported for `Arc<T>` and `Rc<T>` where `T` is sized",
+        )?
+    } else {
+        None
+    };
+    let set_wrapped_type = set_box_inner_type
+        .clone()
+        .or_else(|| set_shared_inner_type.clone());
+    // Returns the wrapped value and the expression which replaces the field with it.
+    // The `Box` is constructed via the declared type, so it's not required to be in scope.
+    let wrap_value = |val: proc_macro2::TokenStream| {
+        if let (Some(box_type), Some(_)) = (set_strip_option, &set_box_inner_type) {
+            (
+                quote!(Some(<#box_type>::new(#val))),
+                quote!(self.#field_name.replace(<#box_type>::new(#val))),
+            )
+        } else {
+            (
+                quote!(<#field_type>::new(#val)),
+                quote!(::core::mem::replace(&mut self.#field_name, <#field_type>::new(#val))),
+            )
+        }
+    };
+    let set_range = match (is_set_enabled, &field_conf.set.range) {
+        (true, Some(range)) => {
+            let inner_field_type = option_inner_type.as_ref().map(FieldType::from_type);
+            let number_type = match (&prop_field_type, inner_field_type) {
+                (FieldType::Number, _) => Some(field_type),
+                (FieldType::Option_(_), Some(FieldType::Number)) if !field_conf.set.full_option => {
+                    option_inner_type.as_ref()
+                }
+                _ => None,
+            };
+            range
+                .enable
+                .check(
+                    number_type,
+                    "the `min` and `max` are only supported for numbers",
+                )?
+                .map(|number_type| range.to_ts(number_type))
+        }
+        _ => None,
+    };
+    let is_fallible = field_conf.set.conversion == SetConversionConf::TryInto_;
+    let is_direct = field_conf.set.conversion == SetConversionConf::Direct;
+    let is_set_checked = is_fallible || field_conf.set.validator.is_some() || set_range.is_some();
+    // The setters with these options are built from pieces, others are written out in full.
+    let on_change = field_conf
+        .set
+        .on_change
+        .as_ref()
+        .map(|func| syn::parse_str::<syn::Path>(func).expect("the path has been checked"));
+    // The hook is called after the field was changed, and it takes the old value as the second
+    // argument if the type of the `set` method is `replace`, for all setters of the field.
+    let is_hook_with_old = field_conf.set.typ == SetTypeConf::Replace;
+    let store_with_hook = |value: proc_macro2::TokenStream, is_own: bool| {
+        if let Some(ref func) = on_change {
+            let this = if is_own {
+                quote!(&mut self)
+            } else {
+                quote!(self)
+            };
+            if is_hook_with_old {
+                quote!(
+                    let old = ::core::mem::replace(&mut self.#field_name, #value);
+                    #func(#this, &old);
+                )
+            } else {
+                quote!(
+                    self.#field_name = #value;
+                    #func(#this);
+                )
+            }
+        } else {
+            quote!(self.#field_name = #value;)
+        }
+    };
+    let replace_with_hook = |replace: proc_macro2::TokenStream| {
+        if let Some(ref func) = on_change {
+            quote!({
+                let old = #replace;
+                #func(self, &old);
+                old
+            })
+        } else {
+            replace
+        }
+    };
+    let set_transform = field_conf
+        .set
+        .transform
+        .as_ref()
+        .map(|func| syn::parse_str::<syn::Path>(func).expect("the path has been checked"));
+    let set_item_type = match (&prop_field_type, &set_vec_item_type) {
+        (_, Some(item_type)) => Some(item_type.clone()),
+        (FieldType::Vector(inner_type), None) => Some(inner_type.clone()),
+        _ => None,
+    };
+    let is_per_element = is_set_enabled
+        && set_transform.is_some()
+        && field_conf
+            .set
+            .per_element
+            .check(
+                set_item_type,
+                "the `per_element` is only supported for `Vec`",
+            )?
+            .is_some();
+    let is_set_customized = is_set_checked
+        || is_direct
+        || field_conf.set.track_change
+        || on_change.is_some()
+        || set_transform.is_some();
+    let set_locked = if field_conf.set.vis.to_ts().is_some() {
+        field_conf.set.locked.check(
+            LockType::from_type(field_type),
+            "the `locked` is only supported for `Mutex<T>` and `RwLock<T>`",
+        )?
+    } else {
+        None
+    };
+    if set_locked.is_some() && (field_conf.set.typ == SetTypeConf::Own || is_set_customized) {
+        return Err(SynError::new(
+            field_type.span(),
+            "the `locked` setter only supports the types `ref`, `none` and `replace`, \
+            and the value could not be checked or changed by other properties",
+        ));
+    }
+    if field_conf.set.track_change
+        && field_conf.set.vis.to_ts().is_some()
+        && (field_conf.set.typ == SetTypeConf::Own || field_conf.set.typ == SetTypeConf::Replace)
+    {
+        return Err(SynError::new(
+            field_name.span(),
+            "the `track_change` setter returns whether the value was changed, \
+            so it could not be used with the types `own` and `replace`",
+        ));
+    }
+    // With `track_change`, the `set` method and its companion setters return whether the value
+    // was changed.
+    let track_store = |value: proc_macro2::TokenStream| {
+        let assert_partial_eq = assert_trait(field_type, quote!(::core::cmp::PartialEq));
+        let store = store_with_hook(quote!(val), false);
+        quote!(
+            #assert_partial_eq
+            let val = #value;
+            let changed = self.#field_name != val;
+            if changed {
+                #store
+            }
+        )
+    };
+    // The opt-in companion setters store the value as is, so the value could not be checked or
+    // transformed by them.
+    let check_as_is = |flag: &str| {
+        if is_set_checked || set_transform.is_some() {
+            Err(SynError::new(
+                field_name.span(),
+                format!(
+                    "the `{}` setter stores the value as is, so it could not be used with \
+                    `fallible`, `validator`, `min`, `max` and `transform`",
+                    flag
+                ),
+            ))
+        } else {
+            Ok(())
+        }
+    };
+    // Only the `set` method tracks the change or writes through the lock,
+    // the `with` method always returns `Self`.
+    let setters = [
+        (
+            field_conf.set.vis,
+            &field_conf.set.name,
+            field_conf.set.typ,
+            &field_conf.set.attrs,
+            field_conf.set.track_change,
+            set_locked.as_ref(),
+        ),
+        (
+            field_conf.with.vis,
+            &field_conf.with.name,
+            SetTypeConf::Own,
+            &MethodAttrsConf::default(),
+            false,
+            None,
+        ),
+    ];
+    for (vis, name, typ, attrs, track_change, locked) in setters.iter() {
+        // The setters which take `self` drop the instance if the output is unused.
+        let must_use = if *typ == SetTypeConf::Own && !field_conf.set.allow_unused {
+            Some(quote!(
+                #[must_use = "this returns the modified value; the original was consumed"]
+            ))
+        } else {
+            None
+        };
+        let method_name = name.complete(field_name)?;
+        if let Some(ts) = vis.to_ts().map(|visibility| {
+            let validator = field_conf.set.validator.as_ref().map(SetValidatorConf::to_ts);
+            if let Some(lock_type) = locked {
+                // The setter takes `&self`, the value is written through the lock.
+                let inner_type = lock_type.inner_type();
+                let lock_method = lock_type.lock_method();
+                let (output_type, store, output) = match typ {
+                    SetTypeConf::Ref => (
+                        Some(quote!(&Self)),
+                        quote!(*guard = val.into();),
+                        Some(quote!(self)),
+                    ),
+                    SetTypeConf::None_ => (None, quote!(*guard = val.into();), None),
+                    SetTypeConf::Replace => (
+                        Some(quote!(#inner_type)),
+                        quote!(),
+                        Some(quote!(::core::mem::replace(&mut *guard, val.into()))),
+                    ),
+                    SetTypeConf::Own => unreachable!(),
+                };
+                return if field_conf.set.try_lock {
+                    let error_type = lock_type.error_type();
+                    let output_type = output_type.unwrap_or_else(|| quote!(()));
+                    let output = output.unwrap_or_else(|| quote!(()));
+                    quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            &self, val: T
+                        ) -> Result<#output_type, #error_type> {
+                            let mut guard = self.#field_name.#lock_method()?;
+                            #store
+                            Ok(#output)
+                        }
+                    )
+                } else {
+                    let output_type = output_type.map(|output_type| quote!(-> #output_type));
+                    quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            &self, val: T
+                        ) #output_type {
+                            let mut guard = self
+                                .#field_name
+                                .#lock_method()
+                                .unwrap_or_else(::std::sync::PoisonError::into_inner);
+                            #store
+                            #output
+                        }
+                    )
+                };
+            }
+            if is_set_customized {
+                // The value type is the type of the converted value before it is stored in the field.
+                let (bound_type, value_type, is_iter, is_option) =
+                    match (&prop_field_type, &set_vec_item_type) {
+                        (_, Some(item_type)) => (
+                            item_type.clone(),
+                            option_inner_type.clone().unwrap(),
+                            true,
+                            true,
+                        ),
+                        (FieldType::Vector(inner_type), None) => {
+                            (inner_type.clone(), field_type.clone(), true, false)
+                        }
+                        (_, None) if set_wrapped_type.is_some() => (
+                            set_wrapped_type.clone().unwrap(),
+                            set_wrapped_type.clone().unwrap(),
+                            false,
+                            false,
+                        ),
+                        (FieldType::Option_(_), None) if !field_conf.set.full_option => (
+                            option_inner_type.clone().unwrap(),
+                            option_inner_type.clone().unwrap(),
+                            false,
+                            true,
+                        ),
+                        _ => (field_type.clone(), field_type.clone(), false, false),
+                    };
+                let input_type = if is_direct {
+                    quote!(#value_type)
+                } else if is_iter {
+                    quote!(impl IntoIterator<Item = T>)
+                } else {
+                    quote!(T)
+                };
+                let (bound, convert) = if is_fallible {
+                    let convert = if is_iter {
+                        quote!(val
+                            .into_iter()
+                            .map(::core::convert::TryInto::try_into)
+                            .collect::<Result<#value_type, _>>()?)
+                    } else {
+                        quote!(::core::convert::TryInto::try_into(val)?)
+                    };
+                    (quote!(::core::convert::TryInto<#bound_type>), convert)
+                } else {
+                    let convert = if is_iter {
+                        quote!(val.into_iter().map(Into::into).collect::<#value_type>())
+                    } else {
+                        quote!(val.into())
+                    };
+                    (quote!(Into<#bound_type>), convert)
+                };
+                let (generics, convert) = if is_direct {
+                    (quote!(), quote!())
+                } else {
+                    (quote!(<T: #bound>), quote!(let val: #value_type = #convert;))
+                };
+                let transform = match set_transform {
+                    Some(ref func) if is_per_element => {
+                        quote!(let val: #value_type = val.into_iter().map(#func).collect();)
+                    }
+                    Some(ref func) => quote!(let val = #func(val);),
+                    None => quote!(),
+                };
+                let (mut error_type, mut where_clause, validate) = match validator {
+                    Some((func, error_type)) => {
+                        let where_clause = if is_fallible {
+                            quote!(where #error_type: From<<T as #bound>::Error>,)
+                        } else {
+                            quote!()
+                        };
+                        (Some(quote!(#error_type)), where_clause, quote!(#func(&val)?;))
+                    }
+                    None if is_fallible => {
+                        (Some(quote!(<T as #bound>::Error)), quote!(), quote!())
+                    }
+                    None => (None, quote!(), quote!()),
+                };
+                let limit = match set_range.clone() {
+                    Some((min, max, true)) => {
+                        let min = min.map(|min| quote!(let val = if val < #min { #min } else { val };));
+                        let max = max.map(|max| quote!(let val = if val > #max { #max } else { val };));
+                        quote!(#min #max)
+                    }
+                    Some((min, max, false)) => {
+                        if let Some(ref error_type) = error_type {
+                            if where_clause.is_empty() {
+                                where_clause = quote!(where);
+                            }
+                            where_clause = quote!(#where_clause #error_type: From<&'static str>,);
+                        } else {
+                            error_type = Some(quote!(&'static str));
+                        }
+                        let min = min.map(|min| {
+                            quote!(if val < #min {
+                                return Err(From::from("the value is less than the minimum"));
+                            })
+                        });
+                        let max = max.map(|max| {
+                            quote!(if val > #max {
+                                return Err(From::from("the value is greater than the maximum"));
+                            })
+                        });
+                        quote!(#min #max)
+                    }
+                    None => quote!(),
+                };
+                let (value, replace) = if set_wrapped_type.is_some() {
+                    wrap_value(quote!(val))
+                } else if is_option {
+                    (
+                        quote!(Some(val)),
+                        quote!(self.#field_name.replace(val)),
+                    )
+                } else {
+                    (
+                        quote!(val),
+                        quote!(::core::mem::replace(&mut self.#field_name, val)),
+                    )
+                };
+                let (receiver, output_type, store, output) = match typ {
+                    _ if *track_change => (
+                        quote!(&mut self),
+                        Some(quote!(bool)),
+                        track_store(value.clone()),
+                        quote!(changed),
+                    ),
+                    SetTypeConf::Ref => (
+                        quote!(&mut self),
+                        Some(quote!(&mut Self)),
+                        store_with_hook(value.clone(), false),
+                        quote!(self),
+                    ),
+                    SetTypeConf::Own => (
+                        quote!(mut self),
+                        Some(quote!(Self)),
+                        store_with_hook(value.clone(), true),
+                        quote!(self),
+                    ),
+                    SetTypeConf::None_ => (
+                        quote!(&mut self),
+                        None,
+                        store_with_hook(value.clone(), false),
+                        quote!(()),
+                    ),
+                    SetTypeConf::Replace => (
+                        quote!(&mut self),
+                        Some(quote!(#field_type)),
+                        quote!(),
+                        replace_with_hook(replace),
+                    ),
+                };
+                return if let Some(error_type) = error_type {
+                    let output_type = output_type.unwrap_or_else(|| quote!(()));
+                    quote!(
+                        #visibility fn #method_name #generics(
+                            #receiver,
+                            val: #input_type
+                        ) -> Result<#output_type, #error_type> #where_clause {
+                            #convert
+                            #transform
+                            #limit
+                            #validate
+                            #store
+                            Ok(#output)
+                        }
+                    )
+                } else {
+                    let output_type = output_type.map(|output_type| quote!(-> #output_type));
+                    let output = if output_type.is_some() {
+                        output
+                    } else {
+                        quote!()
+                    };
+                    quote!(
+                        #visibility fn #method_name #generics(
+                            #receiver,
+                            val: #input_type
+                        ) #output_type {
+                            #convert
+                            #transform
+                            #limit
+                            #store
+                            #output
+                        }
+                    )
+                };
+            }
+            if let Some(ref wrapped_type) = set_wrapped_type {
+                let (value, replace) = wrap_value(quote!(val.into()));
+                return match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<T: Into<#wrapped_type>>(
+                            &mut self, val: T
+                        ) -> &mut Self {
+                            self.#field_name = #value;
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<T: Into<#wrapped_type>>(
+                            mut self, val: T
+                        ) -> Self {
+                            self.#field_name = #value;
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<T: Into<#wrapped_type>>(
+                            &mut self, val: T
+                        ) {
+                            self.#field_name = #value;
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<T: Into<#wrapped_type>>(
+                            &mut self, val: T
+                        ) -> #field_type {
+                            #replace
+                        }
+                    ),
+                };
+            }
+            if let Some(ref item_type) = set_vec_item_type {
+                return match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<T: Into<#item_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) -> &mut Self {
+                            self.#field_name = Some(val.into_iter().map(Into::into).collect());
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<T: Into<#item_type>>(
+                            mut self,
+                            val: impl IntoIterator<Item = T>
+                        ) -> Self {
+                            self.#field_name = Some(val.into_iter().map(Into::into).collect());
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<T: Into<#item_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) {
+                            self.#field_name = Some(val.into_iter().map(Into::into).collect());
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<T: Into<#item_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) -> #field_type {
+                            self.#field_name.replace(val.into_iter().map(Into::into).collect())
+                        }
+                    ),
+                };
+            }
+            let map_types = KeyedType::from_type(field_type).and_then(|keyed_type| {
+                keyed_type
+                    .value_type()
+                    .map(|value_type| (keyed_type.key_type().clone(), value_type.clone()))
+            });
+            if let Some((key_type, value_type)) = map_types {
+                let collect = quote!(val.into_iter().map(|(k, v)| (k.into(), v.into())).collect());
+                return match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<K: Into<#key_type>, V: Into<#value_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = (K, V)>
+                        ) -> &mut Self {
+                            self.#field_name = #collect;
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<K: Into<#key_type>, V: Into<#value_type>>(
+                            mut self,
+                            val: impl IntoIterator<Item = (K, V)>
+                        ) -> Self {
+                            self.#field_name = #collect;
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<K: Into<#key_type>, V: Into<#value_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = (K, V)>
+                        ) {
+                            self.#field_name = #collect;
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<K: Into<#key_type>, V: Into<#value_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = (K, V)>
+                        ) -> #field_type {
+                            ::core::mem::replace(&mut self.#field_name, #collect)
+                        }
+                    ),
+                };
+            }
+            match &prop_field_type {
+                FieldType::Vector(inner_type) => match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) -> &mut Self {
+                            self.#field_name = val.into_iter().map(Into::into).collect();
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            mut self,
+                            val: impl IntoIterator<Item = T>
+                        ) -> Self {
+                            self.#field_name = val.into_iter().map(Into::into).collect();
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) {
+                            self.#field_name = val.into_iter().map(Into::into).collect();
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                           &mut self,
+                           val: impl IntoIterator<Item = T>
+                        ) -> #field_type {
+                            ::core::mem::replace(&mut self.#field_name, val.into_iter().map(Into::into).collect())
+                        }
+                    ),
+                },
+                FieldType::Option_(ref inner_type) if !field_conf.set.full_option => match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            &mut self, val: T
+                        ) -> &mut Self {
+                            self.#field_name = Some(val.into());
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            mut self, val: T
+                        ) -> Self {
+                            self.#field_name = Some(val.into());
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            &mut self, val: T
+                        ) {
+                            self.#field_name = Some(val.into());
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<T: Into<#inner_type>>(
+                            &mut self, val: T
+                        ) -> #field_type {
+                            self.#field_name.replace(val.into())
+                        }
+                    ),
+                },
+                _ => match typ {
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name<T: Into<#field_type>>(
+                            &mut self, val: T
+                        ) -> &mut Self {
+                            self.#field_name = val.into();
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name<T: Into<#field_type>>(
+                            mut self, val: T
+                        ) -> Self {
+                            self.#field_name = val.into();
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name<T: Into<#field_type>>(
+                            &mut self, val: T
+                        ) {
+                            self.#field_name = val.into();
+                        }
+                    ),
+                    SetTypeConf::Replace => quote!(
+                        #visibility fn #method_name<T: Into<#field_type>>(
+                            &mut self, val: T
+                        ) -> #field_type {
+                            ::core::mem::replace(&mut self.#field_name, val.into())
+                        }
+                    ),
+                },
+            }
+        }) {
+            let attrs = attrs.to_ts();
+            property.push(quote!(#must_use #attrs #ts));
+        }
+        if let (Some(visibility), Some(inner_type)) = (vis.to_ts(), set_strip_option) {
+            // The companion setter of `strip_option`, the value is stored as is.
+            if !is_set_checked && set_transform.is_none() {
+                let method_name =
+                    MethodNameConf::suffix("_opt").complete(&name.complete(field_name)?)?;
+                let (generics, input_type, convert) = if is_direct {
+                    (quote!(), quote!(Option<#inner_type>), quote!(val))
+                } else {
+                    (
+                        quote!(<T: Into<Option<#inner_type>>>),
+                        quote!(T),
+                        quote!(val.into()),
+                    )
+                };
+                let store = store_with_hook(convert.clone(), false);
+                let store_own = store_with_hook(convert.clone(), true);
+                let ts = match typ {
+                    _ if *track_change => {
+                        let store = track_store(convert.clone());
+                        quote!(
+                            #visibility fn #method_name #generics(
+                                &mut self, val: #input_type
+                            ) -> bool {
+                                #store
+                                changed
+                            }
+                        )
+                    }
+                    SetTypeConf::Ref => quote!(
+                        #visibility fn #method_name #generics(
+                            &mut self, val: #input_type
+                        ) -> &mut Self {
+                            #store
+                            self
+                        }
+                    ),
+                    SetTypeConf::Own => quote!(
+                        #visibility fn #method_name #generics(
+                            mut self, val: #input_type
+                        ) -> Self {
+                            #store_own
+                            self
+                        }
+                    ),
+                    SetTypeConf::None_ => quote!(
+                        #visibility fn #method_name #generics(
+                            &mut self, val: #input_type
+                        ) {
+                            #store
+                        }
+                    ),
+                    SetTypeConf::Replace => {
+                        let replace = replace_with_hook(
+                            quote!(::core::mem::replace(&mut self.#field_name, #convert)),
+                        );
+                        quote!(
+                            #visibility fn #method_name #generics(
+                                &mut self, val: #input_type
+                            ) -> #field_type {
+                                #replace
+                            }
+                        )
+                    }
+                };
+                let attrs = attrs.to_ts();
+                property.push(quote!(#must_use #attrs #ts));
+            }
+        }
+    }
+    if let Some(visibility) = field_conf
+        .set
+        .vis
+        .to_ts()
+        .filter(|_| field_conf.set.clone_from)
+    {
+        check_as_is("clone_from")?;
+        let method_name =
+            MethodNameConf::suffix("_from").complete(&field_conf.set.name.complete(field_name)?)?;
+        // The trait bound is checked by the call, the error will point to the field type.
+        let clone_from = quote_spanned!(field_type.span()=>
+            ::core::clone::Clone::clone_from(&mut self.#field_name, val)
+        );
+        let clone_from = match on_change {
+            Some(ref func) if is_hook_with_old => quote!(
+                let old = ::core::clone::Clone::clone(&self.#field_name);
+                #clone_from;
+                #func(self, &old);
+            ),
+            Some(ref func) => quote!(#clone_from; #func(self);),
+            None => quote!(#clone_from;),
+        };
+        if field_conf.set.track_change {
+            let assert_partial_eq = assert_trait(field_type, quote!(::core::cmp::PartialEq));
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, val: &#field_type) -> bool {
+                    #assert_partial_eq
+                    let changed = self.#field_name != *val;
+                    if changed {
+                        #clone_from
+                    }
+                    changed
+                }
+            ));
+        } else {
+            property.push(quote!(
+                #visibility fn #method_name(&mut self, val: &#field_type) -> &mut Self {
+                    #clone_from
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf
+        .set
+        .vis
+        .to_ts()
+        .filter(|_| field_conf.set.if_some)
+    {
+        check_as_is("if_some")?;
+        let method_name = MethodNameConf::suffix("_if_some")
+            .complete(&field_conf.set.name.complete(field_name)?)?;
+        let is_option = match prop_field_type {
+            FieldType::Option_(_) => !field_conf.set.full_option,
+            _ => false,
+        };
+        let (generics, input_type, value) = match prop_field_type {
+            _ if is_direct && is_option => {
+                let inner_type = option_inner_type.as_ref().unwrap();
+                (quote!(), quote!(#inner_type), quote!(Some(val)))
+            }
+            _ if is_direct => (quote!(), quote!(#field_type), quote!(val)),
+            FieldType::Vector(ref item_type) => (
+                quote!(<T: Into<#item_type>>),
+                quote!(impl IntoIterator<Item = T>),
+                quote!(val.into_iter().map(Into::into).collect()),
+            ),
+            _ if is_option => {
+                let inner_type = option_inner_type.as_ref().unwrap();
+                (
+                    quote!(<T: Into<#inner_type>>),
+                    quote!(T),
+                    quote!(Some(val.into())),
+                )
+            }
+            _ => (
+                quote!(<T: Into<#field_type>>),
+                quote!(T),
+                quote!(val.into()),
+            ),
+        };
+        if field_conf.set.track_change {
+            let store = track_store(value);
+            property.push(quote!(
+                #visibility fn #method_name #generics(&mut self, val: Option<#input_type>) -> bool {
+                    if let Some(val) = val {
+                        #store
+                        changed
+                    } else {
+                        false
+                    }
+                }
+            ));
+        } else {
+            let store = store_with_hook(value, false);
+            property.push(quote!(
+                #visibility fn #method_name #generics(&mut self, val: Option<#input_type>) -> &mut Self {
+                    if let Some(val) = val {
+                        #store
+                    }
+                    self
+                }
+            ));
+        }
+    }
+    if let Some(visibility) = field_conf.set.vis.to_ts() {
+        // The companion setters which move the value in as is, the return follows the `type`.
+        // The value could be checked by the validator, but it could not be transformed.
+        let setter_name = field_conf.set.name.complete(field_name)?;
+        let moving_setter = |flag: &str, suffix: &str| -> ParseResult<proc_macro2::TokenStream> {
+            if set_transform.is_some() {
+                return Err(SynError::new(
+                    field_name.span(),
+                    format!(
+                        "the `{}` setter moves the value in as is, \
+                        so it could not be used with `transform`",
+                        flag
+                    ),
+                ));
+            }
+            let method_name = MethodNameConf::suffix(suffix).complete(&setter_name)?;
+            let (receiver, output_type, store, output) = match field_conf.set.typ {
+                _ if field_conf.set.track_change => (
+                    quote!(&mut self),
+                    Some(quote!(bool)),
+                    track_store(quote!(val)),
+                    quote!(changed),
+                ),
+                SetTypeConf::Ref => (
+                    quote!(&mut self),
+                    Some(quote!(&mut Self)),
+                    store_with_hook(quote!(val), false),
+                    quote!(self),
+                ),
+                SetTypeConf::Own => (
+                    quote!(mut self),
+                    Some(quote!(Self)),
+                    store_with_hook(quote!(val), true),
+                    quote!(self),
+                ),
+                SetTypeConf::None_ => (
+                    quote!(&mut self),
+                    None,
+                    store_with_hook(quote!(val), false),
+                    quote!(()),
+                ),
+                SetTypeConf::Replace => (
+                    quote!(&mut self),
+                    Some(quote!(#field_type)),
+                    quote!(),
+                    replace_with_hook(quote!(::core::mem::replace(&mut self.#field_name, val))),
+                ),
+            };
+            let must_use = if field_conf.set.typ == SetTypeConf::Own && !field_conf.set.allow_unused
+            {
+                Some(quote!(
+                    #[must_use = "this returns the modified value; the original was consumed"]
+                ))
+            } else {
+                None
+            };
+            let ts = if let Some(validator) = field_conf.set.validator.as_ref() {
+                let (func, error_type) = validator.to_ts();
+                let output_type = output_type.unwrap_or_else(|| quote!(()));
+                quote!(
+                    #visibility fn #method_name(
+                        #receiver,
+                        val: #field_type
+                    ) -> Result<#output_type, #error_type> {
+                        #func(&val)?;
+                        #store
+                        Ok(#output)
+                    }
+                )
+            } else {
+                let output_type = output_type.map(|output_type| quote!(-> #output_type));
+                let output = if output_type.is_some() {
+                    output
+                } else {
+                    quote!()
+                };
+                quote!(
+                    #must_use
+                    #visibility fn #method_name(#receiver, val: #field_type) #output_type {
+                        #store
+                        #output
+                    }
+                )
+            };
+            Ok(ts)
+        };
+        let vec_type = match prop_field_type {
+            FieldType::Vector(_) => Some(field_type),
+            _ => None,
+        };
+        if field_conf
+            .set
+            .vec
+            .check(vec_type, "the `vec` is only supported for `Vec`")?
+            .is_some()
+        {
+            // The vector is moved in, without collecting the items.
+            property.push(moving_setter("vec", "_vec")?);
+        }
+        if set_shared_inner_type.is_some() {
+            // The shared pointer is moved in, without a new allocation.
+            property.push(moving_setter("wrap", "_shared")?);
+        }
+        let string_type = match prop_field_type {
+            FieldType::String_ => Some(field_type),
+            _ => None,
+        };
+        if field_conf
+            .set
+            .fmt
+            .check(string_type, "the `fmt` is only supported for `String`")?
+            .is_some()
+        {
+            check_as_is("fmt")?;
+            // The companion setter which writes the formatted text into the existing buffer.
+            let method_name = MethodNameConf::suffix("_fmt")
+                .complete(&field_conf.set.name.complete(field_name)?)?;
+            let (save_old, hook) = match on_change {
+                Some(ref func) if is_hook_with_old => (
+                    quote!(let old = ::core::clone::Clone::clone(&self.#field_name);),
+                    quote!(#func(self, &old);),
+                ),
+                Some(ref func) => (quote!(), quote!(#func(self);)),
+                None => (quote!(), quote!()),
+            };
+            // Same as `format!`, it panics if a formatting trait implementation returns an error.
+            let write = quote!(
+                self.#field_name.clear();
+                ::core::fmt::Write::write_fmt(&mut self.#field_name, args).expect(
+                    "a formatting trait implementation returned an error \
+                    when the underlying stream did not",
+                );
+            );
+            if field_conf.set.track_change {
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self, args: ::core::fmt::Arguments<'_>) -> bool {
+                        let old = ::core::clone::Clone::clone(&self.#field_name);
+                        #write
+                        let changed = self.#field_name != old;
+                        if changed {
+                            #hook
+                        }
+                        changed
+                    }
+                ));
+            } else {
+                property.push(quote!(
+                    #visibility fn #method_name(&mut self, args: ::core::fmt::Arguments<'_>) -> &mut Self {
+                        #save_old
+                        #write
+                        #hook
+                        self
+                    }
+                ));
+            }
+        }
+    }
+    Ok(property)
+}
@@ -0,0 +1,909 @@
+// Copyright (C) 2019-2021 Boyu Yang
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::HashMap;
+
+use quote::{quote, quote_spanned};
+use syn::{
+    ext::IdentExt, parse::Result as ParseResult, parse_quote, spanned::Spanned, Error as SynError,
+};
+
+use crate::{
+    generate::{FieldType, KeyedType},
+    parse::{ContainerDef, ExtraOptionConf, FieldDef, NoneOrderConf},
+};
+
+pub(crate) fn derive_default(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    if !property.impl_default {
+        return None;
+    }
+    let name = &property.name;
+    // The type parameters are bounded by the implemented trait, as the standard derive macros.
+    let (_, type_generics, _) = property.generics.split_for_impl();
+    let default_generics =
+        bound_generics(&property.generics, parse_quote!(::core::default::Default));
+    let (impl_generics, _, where_clause_opt) = default_generics.split_for_impl();
+    let values = property.fields.iter().map(|f| {
+        let field_name = &f.ident;
+        if let Some(ref expr) = f.conf.default_value {
+            quote!(#field_name: #expr)
+        } else {
+            quote!(#field_name: ::core::default::Default::default())
+        }
+    });
+    Some(quote!(
+        impl #impl_generics ::core::default::Default for #name #type_generics #where_clause_opt {
+            fn default() -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+    ))
+}
+
+pub(crate) fn derive_debug(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+    if !property.debug.enabled {
+        return None;
+    }
+    let name = &property.name;
+    // The type parameters are bounded by the implemented trait, as the standard derive macros.
+    let (_, type_generics, _) = property.generics.split_for_impl();
+    let debug_generics = bound_generics(&property.generics, parse_quote!(::core::fmt::Debug));
+    let (impl_generics, _, where_clause_opt) = debug_generics.split_for_impl();
+    let struct_name = name.unraw().to_string();
+    let fields = property
+        .fields
+        .iter()
+        .filter(|f| !f.conf.debug.skip)
+        .map(|f| {
+            let field_name = &f.ident;
+            let field_str = field_name.unraw().to_string();
+            if f.conf.debug.redact {
+                let placeholder = &f.conf.debug.placeholder;
+                quote!(.field(#field_str, &format_args!("{}", #placeholder)))
+            } else {
+                quote!(.field(#field_str, &self.#field_name))
+            }
+        });
+    Some(quote!(
+        impl #impl_generics ::core::fmt::Debug for #name #type_generics #where_clause_opt {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#struct_name)
+                    #(#fields)*
+                    .finish()
+            }
+        }
+    ))
+}
+
+pub(crate) fn derive_display(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let template = if let Some(ref template) = property.display {
+        template
+    } else {
+        return Ok(None);
+    };
+    let value = template.value();
+    let mut format_str = String::new();
+    let mut args: Vec<&syn::Ident> = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                let _ = chars.next();
+                format_str.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                let _ = chars.next();
+                format_str.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    placeholder.push(c);
+                }
+                if !closed {
+                    return Err(SynError::new(
+                        template.span(),
+                        "there is an unclosed `{` in the display template",
+                    ));
+                }
+                let (field_str, spec) = if let Some(index) = placeholder.find(':') {
+                    placeholder.split_at(index)
+                } else {
+                    (&placeholder[..], "")
+                };
+                let field_str = field_str.trim();
+                let field_name = property
+                    .fields
+                    .iter()
+                    .map(|f| &f.ident)
+                    .find(|ident| ident.unraw() == field_str)
+                    .ok_or_else(|| {
+                        let fields = property
+                            .fields
+                            .iter()
+                            .map(|f| format!("`{}`", f.ident.unraw()))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        SynError::new(
+                            template.span(),
+                            format!(
+                                "unknown field `{}` in the display template, the available fields are: {}",
+                                field_str, fields
+                            ),
+                        )
+                    })?;
+                let index = if let Some(index) = args.iter().position(|arg| *arg == field_name) {
+                    index
+                } else {
+                    args.push(field_name);
+                    args.len() - 1
+                };
+                format_str.push_str(&format!("{{{}{}}}", index, spec));
+            }
+            '}' => {
+                return Err(SynError::new(
+                    template.span(),
+                    "there is an unmatched `}` in the display template, use `}}` to escape it",
+                ));
+            }
+            c => format_str.push(c),
+        }
+    }
+    let name = &property.name;
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let format_lit = syn::LitStr::new(&format_str, template.span());
+    Ok(Some(quote!(
+        impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause_opt {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, #format_lit, #(self.#args),*)
+            }
+        }
+    )))
+}
+
+pub(crate) fn derive_deref(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let mut fields = property.fields.iter().filter(|f| f.conf.deref);
+    let field = if let Some(field) = fields.next() {
+        field
+    } else {
+        return Ok(None);
+    };
+    if let Some(other) = fields.next() {
+        return Err(SynError::new(
+            other.ident.span(),
+            format!(
+                "the `deref` could only be set for one field, but it's already set for the field `{}`",
+                field.ident.unraw()
+            ),
+        ));
+    }
+    let name = &property.name;
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let impl_deref_mut = if field.conf.deref_mut {
+        Some(quote!(
+            impl #impl_generics ::core::ops::DerefMut for #name #type_generics #where_clause_opt {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.#field_name
+                }
+            }
+        ))
+    } else {
+        None
+    };
+    Ok(Some(quote!(
+        impl #impl_generics ::core::ops::Deref for #name #type_generics #where_clause_opt {
+            type Target = #field_type;
+            fn deref(&self) -> &Self::Target {
+                &self.#field_name
+            }
+        }
+
+        #impl_deref_mut
+    )))
+}
+
+pub(crate) fn derive_as_conv(property: &ContainerDef) -> ParseResult<proc_macro2::TokenStream> {
+    let name = &property.name;
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let mut impls = Vec::new();
+    for is_mut in &[false, true] {
+        // The targets are compared as strings, since the same target could not be implemented twice.
+        let mut targets = HashMap::new();
+        for f in property.fields.iter() {
+            let (conf, trait_name) = if *is_mut {
+                (&f.conf.as_mut, "as_mut")
+            } else {
+                (&f.conf.as_ref, "as_ref")
+            };
+            if !conf.enabled {
+                continue;
+            }
+            let field_name = &f.ident;
+            let target = conf.target.as_ref().unwrap_or(&f.ty);
+            if let Some(prev) = targets.insert(quote!(#target).to_string(), field_name) {
+                return Err(SynError::new(
+                    field_name.span(),
+                    format!(
+                        "the `{}` for the target `{}` is already set for the field `{}`",
+                        trait_name,
+                        quote!(#target),
+                        prev.unraw()
+                    ),
+                ));
+            }
+            impls.push(if *is_mut {
+                quote!(
+                    impl #impl_generics ::core::convert::AsMut<#target> for #name #type_generics
+                    #where_clause_opt
+                    {
+                        fn as_mut(&mut self) -> &mut #target {
+                            &mut self.#field_name
+                        }
+                    }
+                )
+            } else {
+                quote!(
+                    impl #impl_generics ::core::convert::AsRef<#target> for #name #type_generics
+                    #where_clause_opt
+                    {
+                        fn as_ref(&self) -> &#target {
+                            &self.#field_name
+                        }
+                    }
+                )
+            });
+        }
+    }
+    Ok(quote!(#(#impls)*))
+}
+
+pub(crate) fn derive_borrow(property: &ContainerDef) -> ParseResult<proc_macro2::TokenStream> {
+    let name = &property.name;
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let ordered = property
+        .fields
+        .iter()
+        .filter(|f| f.conf.ord.number.is_some())
+        .collect::<Vec<_>>();
+    let mut impls = Vec::new();
+    let mut targets = HashMap::new();
+    for f in property.fields.iter().filter(|f| f.conf.borrow.enabled) {
+        let field_name = &f.ident;
+        let target = f.conf.borrow.target.as_ref().unwrap_or(&f.ty);
+        if let Some(prev) = targets.insert(quote!(#target).to_string(), field_name) {
+            return Err(SynError::new(
+                field_name.span(),
+                format!(
+                    "the `borrow` for the target `{}` is already set for the field `{}`",
+                    quote!(#target),
+                    prev.unraw()
+                ),
+            ));
+        }
+        // The `Eq`, `Ord` and `Hash` should be consistent with the borrowed value.
+        if !ordered.is_empty() && (ordered.len() != 1 || ordered[0].ident != *field_name) {
+            return Err(SynError::new(
+                field_name.span(),
+                format!(
+                    "the `borrow` requires that only the field `{}` has the `ord` property, \
+                     since the `PartialEq`, `PartialOrd` and `Hash` should be consistent \
+                     with the borrowed value",
+                    field_name.unraw()
+                ),
+            ));
+        }
+        impls.push(quote!(
+            impl #impl_generics ::core::borrow::Borrow<#target> for #name #type_generics
+            #where_clause_opt
+            {
+                fn borrow(&self) -> &#target {
+                    &self.#field_name
+                }
+            }
+        ));
+        if f.conf.borrow_mut {
+            impls.push(quote!(
+                impl #impl_generics ::core::borrow::BorrowMut<#target> for #name #type_generics
+                #where_clause_opt
+                {
+                    fn borrow_mut(&mut self) -> &mut #target {
+                        &mut self.#field_name
+                    }
+                }
+            ));
+        }
+    }
+    Ok(quote!(#(#impls)*))
+}
+
+pub(crate) fn derive_into_iterator(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let mut fields = property.fields.iter().filter(|f| f.conf.into_iterator);
+    let field = if let Some(field) = fields.next() {
+        field
+    } else {
+        return Ok(None);
+    };
+    if let Some(other) = fields.next() {
+        return Err(SynError::new(
+            other.ident.span(),
+            format!(
+                "the `into_iterator` could only be set for one field, \
+                 but it's already set for the field `{}`",
+                field.ident.unraw()
+            ),
+        ));
+    }
+    let name = &property.name;
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let lt = quote!('__property);
+    // The iterators and the items are taken from the field type, so the collection types are not
+    // required to be in scope; the sets have no mutable iterators.
+    let has_mut = match KeyedType::from_type(field_type) {
+        Some(KeyedType::HashMap(..)) | Some(KeyedType::BTreeMap(..)) => true,
+        Some(KeyedType::HashSet(_)) | Some(KeyedType::BTreeSet(_)) => false,
+        None => match FieldType::from_type(field_type) {
+            FieldType::Vector(_) | FieldType::VecDeque(_) => true,
+            _ => {
+                return Err(SynError::new(
+                    field_type.span(),
+                    "the `into_iterator` is only supported for vectors, maps and sets",
+                ));
+            }
+        },
+    };
+    let owned = quote!(#field_type);
+    let by_ref = quote!(&#lt #field_type);
+    let by_mut = Some(quote!(&#lt mut #field_type)).filter(|_| has_mut);
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let mut ref_generics = property.generics.clone();
+    ref_generics
+        .params
+        .insert(0, syn::parse_quote!('__property));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    let impl_by_mut = by_mut.map(|by_mut| {
+        quote!(
+            impl #ref_impl_generics ::core::iter::IntoIterator
+                for &#lt mut #name #type_generics #where_clause_opt
+            {
+                type Item = <#by_mut as ::core::iter::IntoIterator>::Item;
+                type IntoIter = <#by_mut as ::core::iter::IntoIterator>::IntoIter;
+                fn into_iter(self) -> Self::IntoIter {
+                    (&mut self.#field_name).into_iter()
+                }
+            }
+        )
+    });
+    Ok(Some(quote!(
+        impl #impl_generics ::core::iter::IntoIterator for #name #type_generics #where_clause_opt {
+            type Item = <#owned as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <#owned as ::core::iter::IntoIterator>::IntoIter;
+            fn into_iter(self) -> Self::IntoIter {
+                self.#field_name.into_iter()
+            }
+        }
+
+        impl #ref_impl_generics ::core::iter::IntoIterator
+            for &#lt #name #type_generics #where_clause_opt
+        {
+            type Item = <#by_ref as ::core::iter::IntoIterator>::Item;
+            type IntoIter = <#by_ref as ::core::iter::IntoIterator>::IntoIter;
+            fn into_iter(self) -> Self::IntoIter {
+                (&self.#field_name).into_iter()
+            }
+        }
+
+        #impl_by_mut
+    )))
+}
+
+pub(crate) fn derive_extend(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let mut fields = property.fields.iter().filter(|f| f.conf.impl_extend);
+    let field = if let Some(field) = fields.next() {
+        field
+    } else {
+        return Ok(None);
+    };
+    if let Some(other) = fields.next() {
+        return Err(SynError::new(
+            other.ident.span(),
+            format!(
+                "the `impl_extend` could only be set for one field, \
+                 but it's already set for the field `{}`",
+                field.ident.unraw()
+            ),
+        ));
+    }
+    let name = &property.name;
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let item_type = if let Some(keyed_type) = KeyedType::from_type(field_type) {
+        let key_type = keyed_type.key_type();
+        if let Some(value_type) = keyed_type.value_type() {
+            quote!((#key_type, #value_type))
+        } else {
+            quote!(#key_type)
+        }
+    } else {
+        match FieldType::from_type(field_type) {
+            FieldType::String_ => quote!(char),
+            FieldType::Vector(item_type) | FieldType::VecDeque(item_type) => quote!(#item_type),
+            _ => {
+                return Err(SynError::new(
+                    field_type.span(),
+                    "the `impl_extend` is only supported for strings, vectors, maps and sets",
+                ));
+            }
+        }
+    };
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let impl_from_iter = if field.conf.impl_from_iter {
+        let defaults = property
+            .fields
+            .iter()
+            .filter(|f| f.ident != *field_name)
+            .map(|f| {
+                let name = &f.ident;
+                let ty = &f.ty;
+                quote_spanned!(ty.span()=> #name: <#ty as ::core::default::Default>::default())
+            });
+        Some(quote!(
+            impl #impl_generics ::core::iter::FromIterator<#item_type>
+                for #name #type_generics #where_clause_opt
+            {
+                fn from_iter<I: ::core::iter::IntoIterator<Item = #item_type>>(iter: I) -> Self {
+                    Self {
+                        #field_name: ::core::iter::FromIterator::from_iter(iter),
+                        #(#defaults,)*
+                    }
+                }
+            }
+        ))
+    } else {
+        None
+    };
+    Ok(Some(quote!(
+        impl #impl_generics ::core::iter::Extend<#item_type>
+            for #name #type_generics #where_clause_opt
+        {
+            fn extend<I: ::core::iter::IntoIterator<Item = #item_type>>(&mut self, iter: I) {
+                ::core::iter::Extend::extend(&mut self.#field_name, iter)
+            }
+        }
+
+        #impl_from_iter
+    )))
+}
+
+pub(crate) fn derive_index(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let mut fields = property.fields.iter().filter(|f| f.conf.index);
+    let field = if let Some(field) = fields.next() {
+        field
+    } else {
+        return Ok(None);
+    };
+    if let Some(other) = fields.next() {
+        return Err(SynError::new(
+            other.ident.span(),
+            format!(
+                "the `index` could only be set for one field, but it's already set for the field `{}`",
+                field.ident.unraw()
+            ),
+        ));
+    }
+    let name = &property.name;
+    let field_name = &field.ident;
+    let field_type = &field.ty;
+    let (_, type_generics, _) = property.generics.split_for_impl();
+    let mut generics = property.generics.clone();
+    // The index type, the output type, and the expressions for `index` and `index_mut`.
+    let (index_type, output_type, index_expr, index_mut_expr) =
+        if let Some(keyed_type) = KeyedType::from_type(field_type) {
+            let value_type = keyed_type.value_type().ok_or_else(|| {
+                SynError::new(
+                    field_type.span(),
+                    "the `index` is only supported for vectors and maps",
+                )
+            })?;
+            let key_type = keyed_type.key_type();
+            let bounds = keyed_type.lookup_bounds();
+            generics.params.push(syn::parse_quote!(Q: ?Sized));
+            let where_clause = generics.make_where_clause();
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#key_type: ::core::borrow::Borrow<Q>));
+            where_clause.predicates.push(syn::parse_quote!(Q: #bounds));
+            (
+                quote!(&Q),
+                quote!(#value_type),
+                quote!(&self.#field_name[index]),
+                // The maps don't implement `IndexMut`, the panic is same as `Index`.
+                quote!(self.#field_name.get_mut(index).expect("no entry found for key")),
+            )
+        } else {
+            match FieldType::from_type(field_type) {
+                FieldType::Vector(item_type) | FieldType::VecDeque(item_type) => (
+                    quote!(usize),
+                    quote!(#item_type),
+                    quote!(&self.#field_name[index]),
+                    quote!(&mut self.#field_name[index]),
+                ),
+                _ => {
+                    return Err(SynError::new(
+                        field_type.span(),
+                        "the `index` is only supported for vectors and maps",
+                    ));
+                }
+            }
+        };
+    let (impl_generics, _, where_clause_opt) = generics.split_for_impl();
+    let impl_index_mut = if field.conf.index_mut {
+        Some(quote!(
+            impl #impl_generics ::core::ops::IndexMut<#index_type>
+                for #name #type_generics #where_clause_opt
+            {
+                fn index_mut(&mut self, index: #index_type) -> &mut Self::Output {
+                    #index_mut_expr
+                }
+            }
+        ))
+    } else {
+        None
+    };
+    Ok(Some(quote!(
+        impl #impl_generics ::core::ops::Index<#index_type>
+            for #name #type_generics #where_clause_opt
+        {
+            type Output = #output_type;
+            fn index(&self, index: #index_type) -> &Self::Output {
+                #index_expr
+            }
+        }
+
+        #impl_index_mut
+    )))
+}
+
+pub(crate) fn implement_traits(
+    property: &ContainerDef,
+) -> ParseResult<Option<proc_macro2::TokenStream>> {
+    let name = &property.name;
+    let mut ordered: Vec<_> = property
+        .fields
+        .iter()
+        .filter(|f| f.conf.ord.number.is_some())
+        .collect();
+    let hash = property.hash.check(
+        Some(()).filter(|_| !ordered.is_empty()),
+        "the `hash` requires at least one field which has the `ord` property",
+    )?;
+    if ordered.is_empty() {
+        Ok(None)
+    } else {
+        ordered.sort_by(|f1, f2| {
+            let n1 = f1.conf.ord.number.unwrap();
+            let n2 = f2.conf.ord.number.unwrap();
+            n1.cmp(&n2)
+        });
+        // The sort is stable, so the latter one is the second field in the declaration order.
+        if let Some(f) = ordered
+            .windows(2)
+            .find(|f| f[0].conf.ord.number == f[1].conf.ord.number)
+        {
+            return Err(f[0].same_serial_number_error(f[1]));
+        }
+        // The operands to compare, the keys are extracted if the `by` or `by_len` is set.
+        let operands = |f: &FieldDef| {
+            let field_name = &f.ident;
+            if let ExtraOptionConf::Enable(_) = f.conf.ord.by_len {
+                (
+                    quote!(self.#field_name.len()),
+                    quote!(other.#field_name.len()),
+                )
+            } else if let Some(ref by) = f.conf.ord.by {
+                (
+                    quote!(#by(&self.#field_name)),
+                    quote!(#by(&other.#field_name)),
+                )
+            } else {
+                (quote!(self.#field_name), quote!(other.#field_name))
+            }
+        };
+        let mut total_fields = Vec::new();
+        for f in ordered.iter() {
+            if let ExtraOptionConf::Enable(Some(span)) = f.conf.ord.by_len {
+                if f.conf.ord.by.is_some() {
+                    return Err(SynError::new(
+                        span,
+                        "the `by_len` and `by` of `ord` could not be set together",
+                    ));
+                }
+            }
+            let field_type = FieldType::from_type(&f.ty);
+            let is_collection = match field_type {
+                FieldType::String_
+                | FieldType::Array(_)
+                | FieldType::Vector(_)
+                | FieldType::VecDeque(_) => Some(()),
+                _ => KeyedType::from_type(&f.ty).map(|_| ()),
+            };
+            let _ = f.conf.ord.by_len.check(
+                is_collection,
+                "the `by_len` of `ord` is only supported for collections",
+            )?;
+            // The type of the field doesn't matter if the key is compared.
+            let is_float = f.conf.ord.by.is_none() && is_float_type(&f.ty);
+            if f.conf
+                .ord
+                .total
+                .check(
+                    Some(()).filter(|_| is_float),
+                    "the `total` of `ord` is only supported for floating-point fields",
+                )?
+                .is_some()
+            {
+                total_fields.push(&f.ident);
+            }
+            if let Some((_, span)) = f.conf.ord.none_order {
+                if f.conf.ord.by.is_none() {
+                    match FieldType::from_type(&f.ty) {
+                        FieldType::Option_(_) => {}
+                        _ => {
+                            return Err(SynError::new(
+                                span,
+                                "the order of `None` is only supported for `Option` fields",
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        // The statement to compare an optional field with the `None` placed as configured.
+        let none_order_stmt = |f: &FieldDef,
+                               lhs: &proc_macro2::TokenStream,
+                               rhs: &proc_macro2::TokenStream,
+                               cmp: proc_macro2::TokenStream,
+                               wrap: proc_macro2::TokenStream| {
+            let (none_vs_some, some_vs_none) = match f.conf.ord.none_order {
+                Some((NoneOrderConf::First, _)) => (quote!(Less), quote!(Greater)),
+                Some((NoneOrderConf::Last, _)) => (quote!(Greater), quote!(Less)),
+                None => return None,
+            };
+            let inner = if f.conf.ord.sort_type.is_ascending() {
+                quote!(#cmp(lhs, rhs))
+            } else {
+                quote!(#cmp(rhs, lhs))
+            };
+            Some(quote!(
+                let result = match (&#lhs, &#rhs) {
+                    (::core::option::Option::Some(lhs), ::core::option::Option::Some(rhs)) => {
+                        #inner
+                    }
+                    (::core::option::Option::None, ::core::option::Option::None) => {
+                        #wrap(::core::cmp::Ordering::Equal)
+                    }
+                    (::core::option::Option::None, ::core::option::Option::Some(_)) => {
+                        #wrap(::core::cmp::Ordering::#none_vs_some)
+                    }
+                    (::core::option::Option::Some(_), ::core::option::Option::None) => {
+                        #wrap(::core::cmp::Ordering::#some_vs_none)
+                    }
+                };
+            ))
+        };
+        // The statement to compare the floating-point numbers as a total ordering.
+        let total_stmt = |f: &FieldDef,
+                          lhs: &proc_macro2::TokenStream,
+                          rhs: &proc_macro2::TokenStream,
+                          wrap: proc_macro2::TokenStream| {
+            if !total_fields.contains(&&f.ident) {
+                return None;
+            }
+            let field_type = &f.ty;
+            Some(if f.conf.ord.sort_type.is_ascending() {
+                quote!(let result = #wrap(<#field_type>::total_cmp(&#lhs, &#rhs));)
+            } else {
+                quote!(let result = #wrap(<#field_type>::total_cmp(&#rhs, &#lhs));)
+            })
+        };
+        let partial_eq_stmt = ordered.iter().fold(Vec::new(), |mut r, f| {
+            if !r.is_empty() {
+                r.push(quote!(&&));
+            }
+            let (lhs, rhs) = operands(f);
+            if total_fields.contains(&&f.ident) {
+                let field_type = &f.ty;
+                r.push(quote!(
+                    <#field_type>::total_cmp(&#lhs, &#rhs) == ::core::cmp::Ordering::Equal
+                ));
+            } else {
+                r.push(quote!(#lhs == #rhs));
+            }
+            r
+        });
+        let partial_ord_stmt = ordered.iter().fold(Vec::new(), |mut r, f| {
+            let (lhs, rhs) = operands(f);
+            r.push(
+                if let Some(stmt) = none_order_stmt(
+                    f,
+                    &lhs,
+                    &rhs,
+                    quote!(::core::cmp::PartialOrd::partial_cmp),
+                    quote!(::core::option::Option::Some),
+                ) {
+                    stmt
+                } else if let Some(stmt) =
+                    total_stmt(f, &lhs, &rhs, quote!(::core::option::Option::Some))
+                {
+                    stmt
+                } else if f.conf.ord.sort_type.is_ascending() {
+                    quote!(let result = #lhs.partial_cmp(&#rhs);)
+                } else {
+                    quote!(let result = #rhs.partial_cmp(&#lhs);)
+                },
+            );
+            r.push(quote!(if result != Some(::core::cmp::Ordering::Equal) {
+                return result;
+            }));
+            r
+        });
+        // The type parameters are bounded by the implemented traits, as the standard derive macros.
+        let (_, type_generics, _) = property.generics.split_for_impl();
+        let partial_eq_generics =
+            bound_generics(&property.generics, parse_quote!(::core::cmp::PartialEq));
+        let (partial_eq_impl_generics, _, partial_eq_where_clause_opt) =
+            partial_eq_generics.split_for_impl();
+        let partial_ord_generics =
+            bound_generics(&property.generics, parse_quote!(::core::cmp::PartialOrd));
+        let (partial_ord_impl_generics, _, partial_ord_where_clause_opt) =
+            partial_ord_generics.split_for_impl();
+        let impl_hash = if hash.is_some() {
+            let hash_stmt = ordered.iter().map(|f| {
+                let (lhs, _) = operands(f);
+                if total_fields.contains(&&f.ident) {
+                    // The `total_cmp` treats the numbers as equal only if the bits are same.
+                    let field_type = &f.ty;
+                    quote!(::core::hash::Hash::hash(&<#field_type>::to_bits(#lhs), state);)
+                } else {
+                    quote!(::core::hash::Hash::hash(&#lhs, state);)
+                }
+            });
+            let hash_generics =
+                bound_generics(&property.generics, parse_quote!(::core::hash::Hash));
+            let (hash_impl_generics, _, hash_where_clause_opt) = hash_generics.split_for_impl();
+            Some(quote!(
+                impl #hash_impl_generics ::core::hash::Hash for #name #type_generics
+                    #hash_where_clause_opt
+                {
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        #(#hash_stmt)*
+                    }
+                }
+            ))
+        } else {
+            None
+        };
+        if property.full_ord {
+            let mut ord_stmt = Vec::new();
+            for f in ordered.iter() {
+                let (lhs, rhs) = operands(f);
+                let field_type = &f.ty;
+                let is_total = total_fields.contains(&&f.ident);
+                if f.conf.ord.by.is_none() && is_float_type(field_type) && !is_total {
+                    return Err(SynError::new(
+                        field_type.span(),
+                        "the floating-point numbers don't implement `Ord`, set `total` to compare them via `total_cmp`",
+                    ));
+                }
+                ord_stmt.push(
+                    if let Some(stmt) =
+                        none_order_stmt(f, &lhs, &rhs, quote!(::core::cmp::Ord::cmp), quote!())
+                    {
+                        stmt
+                    } else if let Some(stmt) = total_stmt(f, &lhs, &rhs, quote!()) {
+                        stmt
+                    } else if f.conf.ord.sort_type.is_ascending() {
+                        quote!(let result = ::core::cmp::Ord::cmp(&#lhs, &#rhs);)
+                    } else {
+                        quote!(let result = ::core::cmp::Ord::cmp(&#rhs, &#lhs);)
+                    },
+                );
+                ord_stmt.push(quote!(if result != ::core::cmp::Ordering::Equal {
+                    return result;
+                }));
+            }
+            let eq_generics = bound_generics(&property.generics, parse_quote!(::core::cmp::Eq));
+            let (eq_impl_generics, _, eq_where_clause_opt) = eq_generics.split_for_impl();
+            let ord_generics = bound_generics(&property.generics, parse_quote!(::core::cmp::Ord));
+            let (ord_impl_generics, _, ord_where_clause_opt) = ord_generics.split_for_impl();
+            return Ok(Some(quote!(
+                impl #partial_eq_impl_generics PartialEq for #name #type_generics
+                    #partial_eq_where_clause_opt
+                {
+                    fn eq(&self, other: &Self) -> bool {
+                        #(#partial_eq_stmt)*
+                    }
+                }
+
+                impl #eq_impl_generics Eq for #name #type_generics #eq_where_clause_opt {}
+
+                impl #ord_impl_generics PartialOrd for #name #type_generics #ord_where_clause_opt {
+                    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                        Some(self.cmp(other))
+                    }
+                }
+
+                impl #ord_impl_generics Ord for #name #type_generics #ord_where_clause_opt {
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        #(#ord_stmt)*
+                        ::core::cmp::Ordering::Equal
+                    }
+                }
+
+                #impl_hash
+            )));
+        }
+        let stmts = quote!(
+            impl #partial_eq_impl_generics PartialEq for #name #type_generics
+                #partial_eq_where_clause_opt
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    #(#partial_eq_stmt)*
+                }
+            }
+
+            impl #partial_ord_impl_generics PartialOrd for #name #type_generics
+                #partial_ord_where_clause_opt
+            {
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                    #(#partial_ord_stmt)*
+                    Some(::core::cmp::Ordering::Equal)
+                }
+            }
+
+            #impl_hash
+        );
+        Ok(Some(stmts))
+    }
+}
+
+/// Adds the trait bound to all type parameters.
+fn bound_generics(generics: &syn::Generics, bound: syn::TypeParamBound) -> syn::Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(bound.clone());
+    }
+    generics
+}
+
+pub(crate) fn is_float_type(field_type: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = field_type {
+        type_path.path.is_ident("f32") || type_path.path.is_ident("f64")
+    } else {
+        false
+    }
+}
@@ -8,16 +8,15 @@
 
 extern crate proc_macro;
 
+use std::collections::HashMap;
+
 use quote::quote;
-use syn::{spanned::Spanned, Error as SynError};
+use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
 
 mod generate;
 mod parse;
 
-use crate::{
-    generate::{ClrMethod, FieldType, GetType},
-    parse::{ClrScopeConf, ContainerDef, CrateConfDef, FieldDef, GetTypeConf, SetTypeConf},
-};
+use crate::parse::{ContainerDef, CrateConfDef, FieldDef};
 
 /// Set a global default setting for all `#[derive(Property)]` in the same crate.
 ///
@@ -75,306 +74,160 @@ pub fn property_default(
 #[proc_macro_derive(Property, attributes(property))]
 pub fn derive_property(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let property = syn::parse_macro_input!(input as ContainerDef);
-    let expanded = {
-        let name = &property.name;
-        let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
-        let methods = property.fields.iter().fold(Vec::new(), |mut r, f| {
-            if !f.conf.skip {
-                r.append(&mut derive_property_for_field(f));
-            }
-            r
-        });
-        let impl_methods = quote!(
-            impl #impl_generics #name #type_generics #where_clause_opt {
-                #(#[inline] #methods)*
-            }
-        );
-        if let Some(impl_traits) = implement_traits(&property) {
-            quote!(#impl_methods #impl_traits)
-        } else {
-            impl_methods
-        }
+    let expanded = match derive_property_for_container(&property) {
+        Ok(expanded) => expanded,
+        Err(err) => err.to_compile_error(),
     };
     expanded.into()
 }
 
-fn implement_traits(property: &ContainerDef) -> Option<proc_macro2::TokenStream> {
+fn derive_property_for_container(property: &ContainerDef) -> ParseResult<proc_macro2::TokenStream> {
     let name = &property.name;
-    let mut ordered: Vec<_> = property
-        .fields
-        .iter()
-        .filter(|f| f.conf.ord.number.is_some())
-        .collect();
-    if ordered.is_empty() {
-        None
-    } else {
-        ordered.sort_by(|f1, f2| {
-            let n1 = f1.conf.ord.number.unwrap();
-            let n2 = f2.conf.ord.number.unwrap();
-            n1.cmp(&n2)
-        });
-        let has_same_serial_number = ordered.windows(2).any(|f| {
-            let n1 = f[0].conf.ord.number.unwrap();
-            let n2 = f[1].conf.ord.number.unwrap();
-            n1 == n2
-        });
-        if has_same_serial_number {
-            panic!("there are at least two fields that have same serial number");
+    let (impl_generics, type_generics, where_clause_opt) = property.generics.split_for_impl();
+    let mut methods = Vec::new();
+    let mut trait_methods = Vec::new();
+    let mut method_names = HashMap::new();
+    let has_trait = property.trait_.vis.to_ts().is_some();
+    for f in property.fields.iter().filter(|f| !f.conf.skip) {
+        let mut field_methods = derive_property_for_field(f)?;
+        check_method_names(
+            &mut method_names,
+            MethodOwner::Field(&f.ident),
+            &field_methods,
+        )?;
+        if has_trait {
+            trait_methods.append(&mut derive_property_for_field(&f.for_trait()?)?);
         }
-        let partial_eq_stmt = ordered.iter().fold(Vec::new(), |mut r, f| {
-            if !r.is_empty() {
-                r.push(quote!(&&));
-            }
-            let field_name = &f.ident;
-            r.push(quote!(self.#field_name == other.#field_name));
-            r
-        });
-        let partial_ord_stmt = ordered.iter().fold(Vec::new(), |mut r, f| {
-            let field_name = &f.ident;
-            r.push(if f.conf.ord.sort_type.is_ascending() {
-                quote!(let result = self.#field_name.partial_cmp(&other.#field_name);)
-            } else {
-                quote!(let result = other.#field_name.partial_cmp(&self.#field_name);)
-            });
-            r.push(quote!(if result != Some(::core::cmp::Ordering::Equal) {
-                return result;
-            }));
-            r
-        });
-        let stmts = quote!(
-            impl PartialEq for #name {
-                fn eq(&self, other: &Self) -> bool {
-                    #(#partial_eq_stmt)*
-                }
-            }
-
-            impl PartialOrd for #name {
-                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
-                    #(#partial_ord_stmt)*
-                    Some(::core::cmp::Ordering::Equal)
-                }
-            }
-        );
-        Some(stmts)
+        if !has_trait || !property.trait_.only {
+            methods.append(&mut field_methods);
+        }
+    }
+    let impl_trait = generate::derive_trait(property, &trait_methods)?;
+    let container_methods = vec![
+        (
+            "reset_all",
+            generate::derive_reset_all(property).into_iter().collect(),
+        ),
+        (
+            "fields_mut",
+            generate::derive_fields_mut(property).into_iter().collect(),
+        ),
+        (
+            "parts",
+            generate::derive_parts(property).unwrap_or_default(),
+        ),
+        (
+            "sort_key",
+            generate::derive_sort_key(property)?.into_iter().collect(),
+        ),
+        (
+            "merge",
+            generate::derive_merge(property).into_iter().collect(),
+        ),
+        ("new", generate::derive_new(property)?.into_iter().collect()),
+        (
+            "fields_const",
+            generate::derive_field_names(property).into_iter().collect(),
+        ),
+    ];
+    for (option, mut container_methods) in container_methods {
+        check_method_names(
+            &mut method_names,
+            MethodOwner::Container(option),
+            &container_methods,
+        )?;
+        methods.append(&mut container_methods);
     }
+    if property.builder.to_ts().is_some() {
+        let method_name = syn::Ident::new("builder", proc_macro2::Span::call_site());
+        check_method_name(
+            &mut method_names,
+            MethodOwner::Container("builder"),
+            method_name,
+        )?;
+    }
+    let fields_const = generate::derive_fields_const(property);
+    let impl_methods = quote!(
+        impl #impl_generics #name #type_generics #where_clause_opt {
+            #fields_const
+            #(#[inline] #methods)*
+        }
+    );
+    let builder = generate::derive_builder(property)?;
+    let patch = generate::derive_patch(property)?;
+    let impl_traits = generate::implement_traits(property)?;
+    let impl_default = generate::derive_default(property);
+    let impl_debug = generate::derive_debug(property);
+    let impl_deref = generate::derive_deref(property)?;
+    let impl_as_conv = generate::derive_as_conv(property)?;
+    let impl_borrow = generate::derive_borrow(property)?;
+    let impl_into_iterator = generate::derive_into_iterator(property)?;
+    let impl_extend = generate::derive_extend(property)?;
+    let impl_index = generate::derive_index(property)?;
+    let impl_display = generate::derive_display(property)?;
+    Ok(quote!(
+        #impl_methods #impl_trait #impl_traits #impl_default #impl_debug #impl_display
+        #impl_deref #impl_as_conv #impl_borrow #impl_into_iterator #impl_extend #impl_index
+        #builder #patch
+    ))
 }
 
-fn derive_property_for_field(field: &FieldDef) -> Vec<proc_macro2::TokenStream> {
-    let mut property = Vec::new();
-    let field_type = &field.ty;
-    let field_name = &field.ident;
-    let field_conf = &field.conf;
-    let prop_field_type = FieldType::from_type(field_type);
-    if let Some(ts) = field_conf.get.vis.to_ts().map(|visibility| {
-        let method_name = field_conf.get.name.complete(field_name);
-        let get_type = match field_conf.get.typ {
-            GetTypeConf::Auto => GetType::from_field_type(&prop_field_type),
-            GetTypeConf::Ref => GetType::Ref,
-            GetTypeConf::Copy_ => GetType::Copy_,
-            GetTypeConf::Clone_ => GetType::Clone_,
-        };
-        match get_type {
-            GetType::Ref => quote!(
-                #visibility fn #method_name(&self) -> &#field_type {
-                    &self.#field_name
-                }
-            ),
-            GetType::Copy_ => quote!(
-                #visibility fn #method_name(&self) -> #field_type {
-                    self.#field_name
-                }
-            ),
-            GetType::Clone_ => quote!(
-                #visibility fn #method_name(&self) -> #field_type {
-                    self.#field_name.clone()
-                }
-            ),
-            GetType::String_ => quote!(
-                #visibility fn #method_name(&self) -> &str {
-                    &self.#field_name[..]
-                }
-            ),
-            GetType::Slice(field_type) => quote!(
-                #visibility fn #method_name(&self) -> &#field_type {
-                    &self.#field_name[..]
-                }
-            ),
-            GetType::Option_(field_type) => quote!(
-                #visibility fn #method_name(&self) -> Option<&#field_type> {
-                    self.#field_name.as_ref()
-                }
-            ),
-        }
-    }) {
-        property.push(ts);
-    }
-    if let Some(ts) = field_conf.set.vis.to_ts().map(|visibility| {
-        let method_name = field_conf.set.name.complete(field_name);
-        match &prop_field_type {
-            FieldType::Vector(inner_type) => match field_conf.set.typ {
-                SetTypeConf::Ref => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                       &mut self,
-                       val: impl IntoIterator<Item = T>
-                    ) -> &mut Self {
-                        self.#field_name = val.into_iter().map(Into::into).collect();
-                        self
-                    }
-                ),
-                SetTypeConf::Own => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        mut self,
-                        val: impl IntoIterator<Item = T>
-                    ) -> Self {
-                        self.#field_name = val.into_iter().map(Into::into).collect();
-                        self
-                    }
-                ),
-                SetTypeConf::None_ => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                       &mut self,
-                       val: impl IntoIterator<Item = T>
-                    ) {
-                        self.#field_name = val.into_iter().map(Into::into).collect();
-                    }
-                ),
-                SetTypeConf::Replace => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                       &mut self,
-                       val: impl IntoIterator<Item = T>
-                    ) -> #field_type {
-                        ::core::mem::replace(&mut self.#field_name, val.into_iter().map(Into::into).collect())
-                    }
-                ),
-            },
-            FieldType::Option_(ref inner_type) if !field_conf.set.full_option => match field_conf.set.typ {
-                SetTypeConf::Ref => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        &mut self, val: T
-                    ) -> &mut Self {
-                        self.#field_name = Some(val.into());
-                        self
-                    }
-                ),
-                SetTypeConf::Own => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        mut self, val: T
-                    ) -> Self {
-                        self.#field_name = Some(val.into());
-                        self
-                    }
-                ),
-                SetTypeConf::None_ => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        &mut self, val: T
-                    ) {
-                        self.#field_name = Some(val.into());
-                    }
-                ),
-                SetTypeConf::Replace => quote!(
-                    #visibility fn #method_name<T: Into<#inner_type>>(
-                        &mut self, val: T
-                    ) -> #field_type {
-                        self.#field_name.replace(val.into())
-                    }
-                ),
-            },
-            _ => match field_conf.set.typ {
-                SetTypeConf::Ref => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        &mut self, val: T
-                    ) -> &mut Self {
-                        self.#field_name = val.into();
-                        self
-                    }
-                ),
-                SetTypeConf::Own => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        mut self, val: T
-                    ) -> Self {
-                        self.#field_name = val.into();
-                        self
-                    }
-                ),
-                SetTypeConf::None_ => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        &mut self, val: T
-                    ) {
-                        self.#field_name = val.into();
-                    }
-                ),
-                SetTypeConf::Replace => quote!(
-                    #visibility fn #method_name<T: Into<#field_type>>(
-                        &mut self, val: T
-                    ) -> #field_type {
-                        ::core::mem::replace(&mut self.#field_name, val.into())
-                    }
-                ),
-            },
+fn derive_property_for_field(field: &FieldDef) -> ParseResult<Vec<proc_macro2::TokenStream>> {
+    let mut property = generate::derive_getters(field)?;
+    property.append(&mut generate::derive_setters(field)?);
+    property.append(&mut generate::derive_mutators(field)?);
+    property.append(&mut generate::derive_collection_methods(field)?);
+    Ok(property)
+}
+
+/// The owner of a generated method, a field or an option of the container.
+#[derive(Clone, Copy, PartialEq)]
+enum MethodOwner<'a> {
+    Field(&'a syn::Ident),
+    Container(&'static str),
+}
+
+impl<'a> std::fmt::Display for MethodOwner<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MethodOwner::Field(field_name) => write!(f, "the field `{}`", field_name),
+            MethodOwner::Container(option) => write!(f, "the `{}` of the container", option),
         }
-    }) {
-        property.push(ts);
     }
-    if let Some(ts) = field_conf.mut_.vis.to_ts().map(|visibility| {
-        let method_name = field_conf.mut_.name.complete(field_name);
-        quote!(
-            #visibility fn #method_name(&mut self) -> &mut #field_type {
-                &mut self.#field_name
-            }
-        )
-    }) {
-        property.push(ts);
+}
+
+fn check_method_names<'a>(
+    method_names: &mut HashMap<String, MethodOwner<'a>>,
+    owner: MethodOwner<'a>,
+    methods: &[proc_macro2::TokenStream],
+) -> ParseResult<()> {
+    for method in methods {
+        let method_name = syn::parse2::<syn::ImplItemMethod>(method.to_owned())?
+            .sig
+            .ident;
+        check_method_name(method_names, owner, method_name)?;
     }
-    if let Some(ts) = field_conf.clr.vis.to_ts().and_then(|visibility| {
-        let method_name = field_conf.clr.name.complete(field_name);
-        let auto_clr_method = ClrMethod::from_field_type(&prop_field_type);
-        let clr_method = match field_conf.clr.scope {
-            ClrScopeConf::Auto => auto_clr_method,
-            ClrScopeConf::Option_ => {
-                if auto_clr_method == ClrMethod::SetNone {
-                    auto_clr_method
-                } else {
-                    ClrMethod::None_
-                }
-            }
-            ClrScopeConf::All => {
-                if auto_clr_method == ClrMethod::None_ {
-                    ClrMethod::SetDefault
-                } else {
-                    auto_clr_method
-                }
-            }
+    Ok(())
+}
+
+fn check_method_name<'a>(
+    method_names: &mut HashMap<String, MethodOwner<'a>>,
+    owner: MethodOwner<'a>,
+    method_name: syn::Ident,
+) -> ParseResult<()> {
+    if let Some(prev_owner) = method_names.insert(method_name.to_string(), owner) {
+        let msg = if prev_owner == owner {
+            format!(
+                "the method `{}` is generated twice for {}",
+                method_name, owner
+            )
+        } else {
+            format!(
+                "the method `{}` for {} is already generated for {}",
+                method_name, owner, prev_owner
+            )
         };
-        match clr_method {
-            ClrMethod::SetZero => Some(quote!(
-                #visibility fn #method_name(&mut self) {
-                    self.#field_name = 0;
-                }
-            )),
-            ClrMethod::SetNone => Some(quote!(
-                #visibility fn #method_name(&mut self) {
-                    self.#field_name =None;
-                }
-            )),
-            ClrMethod::SetDefault => Some(quote!(
-                #visibility fn #method_name(&mut self) {
-                    self.#field_name = Default::default();
-                }
-            )),
-            ClrMethod::CallClear => Some(quote!(
-                #visibility fn #method_name(&mut self) {
-                    self.#field_name.clear();
-                }
-            )),
-            ClrMethod::FillWithDefault => Some(quote!(
-                #visibility fn #method_name(&mut self) {
-                    self.#field_name.fill_with(Default::default);
-                }
-            )),
-            ClrMethod::None_ => None,
-        }
-    }) {
-        property.push(ts);
+        return Err(SynError::new(method_name.span(), msg));
     }
-    property
+    Ok(())
 }
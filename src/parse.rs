@@ -6,29 +6,112 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::sync::{
-    atomic::{AtomicUsize, Ordering},
-    Once,
+use std::{
+    cell::OnceCell,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 use quote::quote;
-use syn::{parse::Result as ParseResult, spanned::Spanned, Error as SynError};
+use syn::{ext::IdentExt, parse::Result as ParseResult, spanned::Spanned, Error as SynError};
 
 const ATTR_NAME: &str = "property";
 const SKIP: &str = "skip";
+const INTO: &str = "into";
+const BUILDER: &str = "builder";
+const PATCH: &str = "patch";
+const NEW: &str = "new";
+const FIELDS_MUT: &str = "fields_mut";
+const SORT_KEY: &str = "sort_key";
+const PARTS: &str = "parts";
+const DEREF: &str = "deref";
+const AS_REF: &str = "as_ref";
+const AS_MUT: &str = "as_mut";
+const BORROW: &str = "borrow";
+const INTO_ITERATOR: &str = "into_iterator";
+const IMPL_EXTEND: &str = "impl_extend";
+const INDEX: &str = "index";
+const FIELDS_CONST: &str = "fields_const";
+const HASH: &str = "hash";
+const IMPL_DEFAULT: &str = "impl_default";
+const DEBUG: &str = "debug";
+const DISPLAY: &str = "display";
+const DEFAULT: &str = "default";
+const TRAIT: &str = "trait";
 const NAME_OPTION: (&str, Option<&[&str]>) = ("name", None);
+const PLACEHOLDER_OPTION: (&str, Option<&[&str]>) = ("placeholder", None);
+const TARGET_OPTION: (&str, Option<&[&str]>) = ("target", None);
 const PREFIX_OPTION: (&str, Option<&[&str]>) = ("prefix", None);
 const SUFFIX_OPTION: (&str, Option<&[&str]>) = ("suffix", None);
+const TEMPLATE_OPTION: (&str, Option<&[&str]>) = ("template", None);
 const VISIBILITY_OPTIONS: &[&str] = &["disable", "public", "crate", "private"];
-const GET_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["auto", "ref", "copy", "clone"]));
+const GET_TYPE_OPTIONS: (&str, Option<&[&str]>) = (
+    "type",
+    Some(&["auto", "ref", "copy", "clone", "try_ref", "bytes"]),
+);
+const GET_ERROR_OPTION: (&str, Option<&[&str]>) = ("error", None);
+const GET_WITH_OPTION: (&str, Option<&[&str]>) = ("with", None);
+const GET_RETURNS_OPTION: (&str, Option<&[&str]>) = ("returns", None);
 const SET_TYPE_OPTIONS: (&str, Option<&[&str]>) =
     ("type", Some(&["ref", "own", "none", "replace"]));
-const SET_OPTION_FULL_OPTION: &[&str] = &["full_option"];
+const SET_VALIDATOR_OPTION: (&str, Option<&[&str]>) = ("validator", None);
+const SET_ERROR_OPTION: (&str, Option<&[&str]>) = ("error", None);
+const SET_ON_CHANGE_OPTION: (&str, Option<&[&str]>) = ("on_change", None);
+const SET_TRANSFORM_OPTION: (&str, Option<&[&str]>) = ("transform", None);
+const SET_MIN_OPTION: (&str, Option<&[&str]>) = ("min", None);
+const SET_MAX_OPTION: (&str, Option<&[&str]>) = ("max", None);
+const GET_OPTION_INDEXED: &[&str] = &["indexed"];
+const GET_OPTION_RAW_OPTION: &[&str] = &["raw_option"];
+const GET_OPTION_NO_COERCE: &[&str] = &["no_coerce"];
+const GET_OPTION_SLICE_RANGE: &[&str] = &["slice_range"];
+const SET_OPTION_OPTION_MODES: &[&str] = &["full_option", "strip_option"];
+const SET_OPTION_CONVERSIONS: &[&str] = &["fallible", "direct"];
+const SET_OPTION_CLAMP: &[&str] = &["clamp"];
+const SET_OPTION_CLONE_FROM: &[&str] = &["clone_from"];
+const SET_OPTION_IF_SOME: &[&str] = &["if_some"];
+const SET_OPTION_TRACK_CHANGE: &[&str] = &["track_change"];
+const SET_OPTION_ALLOW_UNUSED: &[&str] = &["allow_unused"];
+const SET_OPTION_VEC: &[&str] = &["vec"];
+const SET_OPTION_FMT: &[&str] = &["fmt"];
+const SET_OPTION_PER_ELEMENT: &[&str] = &["per_element"];
+const SET_OPTION_WRAP: &[&str] = &["wrap"];
+const SET_OPTION_LOCKED: &[&str] = &["locked"];
+const SET_OPTION_TRY: &[&str] = &["try"];
+const ATTRS_OPTION: &str = "attrs";
+const MUT_OPTION_INDEXED: &[&str] = &["indexed"];
+const MUT_OPTION_RAW: &[&str] = &["raw"];
+const MUT_OPTION_OR_DEFAULT: &[&str] = &["or_default"];
+const MUT_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("type", Some(&["auto", "slice", "inner"]));
 const CLR_TYPE_OPTIONS: (&str, Option<&[&str]>) = ("scope", Some(&["auto", "option", "all"]));
+const CLR_OPTION_CHAIN: &[&str] = &["chain"];
+const WITH_MUT_OPTION_FLATTEN: &[&str] = &["flatten"];
+const ENDS_OPTION_MUTABLE: &[&str] = &["mutable"];
+const DRAIN_OPTION_RANGE: &[&str] = &["range"];
+const GET_OR_INSERT_OPTION_LAZY: &[&str] = &["lazy"];
+const TRAIT_OPTION_ONLY: &[&str] = &["only"];
+const DEREF_OPTION_MUT: &[&str] = &["mut"];
+const IMPL_EXTEND_OPTION_FROM_ITER: &[&str] = &["from_iter"];
+const FIELDS_CONST_OPTION_METHOD: &[&str] = &["method"];
+const DEBUG_OPTION_FIELD: &[&str] = &["redact", "skip"];
+const RESET_OPTION_SKIP: &[&str] = &["skip"];
+const MERGE_OPTION_SKIP: &[&str] = &["skip"];
+const MERGE_OPTION_OPTIONS: &[&str] = &["options"];
+const MERGE_OPTION_OVERWRITE: &[&str] = &["overwrite"];
+const NEW_OPTION_SKIP: &[&str] = &["skip", "default"];
+const NEW_OPTION_CONST: &[&str] = &["const"];
+const NEW_OPTION_OPTIONAL_DEFAULTS: &[&str] = &["optional_defaults"];
 const SORT_TYPE_OPTIONS: &[&str] = &["asc", "desc"];
+const ORD_OPTION_FULL: &str = "full";
+const ORD_OPTION_ALL: &str = "all";
+const ORD_OPTION_SKIP: &str = "skip";
+const ORD_OPTION_BY: &str = "by";
+const ORD_OPTION_TOTAL: &str = "total";
+const ORD_OPTION_BY_LEN: &str = "by_len";
+const ORD_OPTION_NONE: &[&str] = &["none_first", "none_last"];
 
-static INIT_DEFAULT: Once = Once::new();
-static mut CRATE_CONF: Option<FieldConf> = None;
+thread_local! {
+    // The spans inside the config are not `Send`, and a crate is expanded in a single thread.
+    static CRATE_CONF: OnceCell<FieldConf> = const { OnceCell::new() };
+}
 static CALL_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -45,6 +128,21 @@ pub(crate) struct CrateConfDef {
 pub(crate) struct ContainerDef {
     pub(crate) name: syn::Ident,
     pub(crate) generics: syn::Generics,
+    pub(crate) builder: VisibilityConf,
+    pub(crate) reset_all: ResetAllConf,
+    pub(crate) merge: MergeConf,
+    pub(crate) patch: PatchConf,
+    pub(crate) new: NewConf,
+    pub(crate) fields_mut: FieldsMutConf,
+    pub(crate) sort_key: SortKeyConf,
+    pub(crate) parts: PartsConf,
+    pub(crate) trait_: TraitConf,
+    pub(crate) fields_const: FieldsConstConf,
+    pub(crate) full_ord: bool,
+    pub(crate) hash: ExtraOptionConf,
+    pub(crate) impl_default: bool,
+    pub(crate) debug: DebugConf,
+    pub(crate) display: Option<syn::LitStr>,
     pub(crate) fields: Vec<FieldDef>,
 }
 
@@ -60,9 +158,11 @@ pub(crate) enum GetTypeConf {
     Ref,
     Copy_,
     Clone_,
+    TryRef,
+    Bytes,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum SetTypeConf {
     Ref,
     Own,
@@ -70,6 +170,14 @@ pub(crate) enum SetTypeConf {
     Replace,
 }
 
+/// How the argument of a setter is converted to the field type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetConversionConf {
+    Into_,
+    TryInto_,
+    Direct,
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum ClrScopeConf {
     Auto,
@@ -91,10 +199,60 @@ pub(crate) enum SortTypeConf {
     Descending,
 }
 
+/// Where the `None` is placed when an optional field is compared.
+#[derive(Clone, Copy)]
+pub(crate) enum NoneOrderConf {
+    First,
+    Last,
+}
+
+/// An extra option which only makes sense for some kinds of fields.
+///
+/// If it was set as a field property, the span is kept to report errors for unsupported fields;
+/// otherwise, the unsupported fields are just ignored.
+#[derive(Clone, Copy)]
+pub(crate) enum ExtraOptionConf {
+    Disable,
+    Enable(Option<proc_macro2::Span>),
+}
+
+/// The attributes for a generated method, they are kept as strings since the configuration could
+/// be shared by different macro invocations.
+#[derive(Clone, Default)]
+pub(crate) struct MethodAttrsConf(Vec<String>);
+
 #[derive(Clone)]
 pub(crate) enum MethodNameConf {
     Name(String),
     Format { prefix: String, suffix: String },
+    Template(String),
+}
+
+/// A custom getter: the function is called with a reference of the field, and its output type
+/// is the return type of the getter.
+#[derive(Clone)]
+pub(crate) struct GetWithConf {
+    func: String,
+    returns: String,
+}
+
+/// A validator for setters: the function is called with a reference of the converted value, and
+/// returns `Result<(), E>` where `E` could be converted into the error type.
+#[derive(Clone)]
+pub(crate) struct SetValidatorConf {
+    func: String,
+    error: String,
+}
+
+/// A range for setters of numbers, both bounds are inclusive.
+///
+/// The bounds are number literals without suffixes, the type of the field is used as the suffix.
+#[derive(Clone)]
+pub(crate) struct SetRangeConf {
+    min: Option<String>,
+    max: Option<String>,
+    clamp: bool,
+    pub(crate) enable: ExtraOptionConf,
 }
 
 #[derive(Clone)]
@@ -102,6 +260,13 @@ pub(crate) struct GetFieldConf {
     pub(crate) vis: VisibilityConf,
     pub(crate) name: MethodNameConf,
     pub(crate) typ: GetTypeConf,
+    pub(crate) indexed: ExtraOptionConf,
+    pub(crate) slice_range: ExtraOptionConf,
+    pub(crate) raw_option: ExtraOptionConf,
+    pub(crate) no_coerce: bool,
+    pub(crate) with: Option<GetWithConf>,
+    pub(crate) error: Option<String>,
+    pub(crate) attrs: MethodAttrsConf,
 }
 
 #[derive(Clone)]
@@ -109,13 +274,41 @@ pub(crate) struct SetFieldConf {
     pub(crate) vis: VisibilityConf,
     pub(crate) name: MethodNameConf,
     pub(crate) typ: SetTypeConf,
+    pub(crate) typ_span: Option<proc_macro2::Span>,
     pub(crate) full_option: bool,
+    pub(crate) strip_option: ExtraOptionConf,
+    pub(crate) conversion: SetConversionConf,
+    pub(crate) conversion_span: Option<proc_macro2::Span>,
+    pub(crate) validator: Option<SetValidatorConf>,
+    pub(crate) range: Option<SetRangeConf>,
+    pub(crate) clone_from: bool,
+    pub(crate) if_some: bool,
+    pub(crate) vec: ExtraOptionConf,
+    pub(crate) fmt: ExtraOptionConf,
+    pub(crate) transform: Option<String>,
+    pub(crate) per_element: ExtraOptionConf,
+    pub(crate) wrap: ExtraOptionConf,
+    pub(crate) locked: ExtraOptionConf,
+    pub(crate) try_lock: bool,
+    pub(crate) track_change: bool,
+    pub(crate) on_change: Option<String>,
+    pub(crate) allow_unused: bool,
+    pub(crate) attrs: MethodAttrsConf,
 }
 
 #[derive(Clone)]
 pub(crate) struct MutFieldConf {
     pub(crate) vis: VisibilityConf,
     pub(crate) name: MethodNameConf,
+    pub(crate) indexed: ExtraOptionConf,
+    pub(crate) raw: bool,
+    /// Return a slice for arrays, `Vec` and `String`, set via `type = "slice"`.
+    pub(crate) slice: ExtraOptionConf,
+    /// Return `Option<&mut T>` for `Option<T>` even if `raw` is set, set via `type = "inner"`.
+    pub(crate) inner: ExtraOptionConf,
+    /// Generate a `_or_default` accessor which inserts the default value for `Option<T>`.
+    pub(crate) or_default: ExtraOptionConf,
+    pub(crate) attrs: MethodAttrsConf,
 }
 
 #[derive(Clone)]
@@ -123,12 +316,163 @@ pub(crate) struct ClrFieldConf {
     pub(crate) vis: VisibilityConf,
     pub(crate) name: MethodNameConf,
     pub(crate) scope: ClrScopeConf,
+    /// Return `&mut Self` for chaining.
+    pub(crate) chain: ExtraOptionConf,
+    /// If it was set as a field property, the field type should be supported by the scope.
+    pub(crate) explicit: ExtraOptionConf,
+}
+
+#[derive(Clone)]
+pub(crate) struct IntoFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: MethodNameConf,
+}
+
+/// An opt-in method which only makes sense for some kinds of fields.
+#[derive(Clone)]
+pub(crate) struct OptInFieldConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: MethodNameConf,
+    /// If it was set as a field property, the field type should be supported.
+    pub(crate) explicit: ExtraOptionConf,
+}
+
+/// The method which resets all fields.
+///
+/// It's set as a crate or container property, except `skip`, which is set as a field property.
+#[derive(Clone)]
+pub(crate) struct ResetAllConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+    pub(crate) skip: bool,
+}
+
+/// The implementation of `Debug`.
+///
+/// It's enabled as a crate or container property, `redact` and `skip` are set as field properties.
+#[derive(Clone)]
+pub(crate) struct DebugConf {
+    pub(crate) enabled: bool,
+    /// Print the placeholder instead of the value.
+    pub(crate) redact: bool,
+    pub(crate) skip: bool,
+    pub(crate) placeholder: String,
+}
+
+/// The method which borrows all fields mutably at the same time.
+///
+/// It's set as a crate or container property.
+#[derive(Clone)]
+pub(crate) struct FieldsMutConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+}
+
+/// The method which returns a tuple of references to the ordered fields.
+///
+/// It's set as a crate or container property.
+#[derive(Clone)]
+pub(crate) struct SortKeyConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+}
+
+/// The methods which convert the instance from and into a tuple of the fields.
+///
+/// It's set as a crate or container property.
+#[derive(Clone)]
+pub(crate) struct PartsConf {
+    pub(crate) vis: VisibilityConf,
+}
+
+/// The trait which contains the methods for fields, it's implemented for the container.
+///
+/// It's set as a container property.
+#[derive(Clone)]
+pub(crate) struct TraitConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: Option<syn::Ident>,
+    /// Don't generate the inherent methods for fields.
+    pub(crate) only: bool,
+}
+
+/// The implementation of `AsRef`, `AsMut` or `Borrow` for a field.
+///
+/// It's set as a field property, the target is the type of the field if it's not set.
+#[derive(Clone, Default)]
+pub(crate) struct AsConvConf {
+    pub(crate) enabled: bool,
+    pub(crate) target: Option<syn::Type>,
+}
+
+/// The constant which lists the names of the fields.
+///
+/// It's set as a crate or container property.
+#[derive(Clone)]
+pub(crate) struct FieldsConstConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+    /// Generate the `field_names` method too.
+    pub(crate) method: bool,
+}
+
+/// The method which moves all fields from another instance.
+///
+/// It's set as a crate or container property, except `skip`, which is set as a field property.
+///
+/// With `options`, only the `None` fields are filled, and other fields are ignored unless
+/// `overwrite` is set.
+#[derive(Clone)]
+pub(crate) struct MergeConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: Option<syn::Ident>,
+    pub(crate) options: bool,
+    pub(crate) overwrite: bool,
+    pub(crate) skip: bool,
+}
+
+/// The constructor which takes all fields.
+///
+/// It's set as a crate or container property, except `skip` (or `default`), which is set as a
+/// field property, the field is not taken and the default value is used.
+///
+/// With `optional_defaults`, the `Option` fields and the fields with `default` are not taken, too.
+#[derive(Clone)]
+pub(crate) struct NewConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: syn::Ident,
+    pub(crate) is_const: bool,
+    pub(crate) optional_defaults: bool,
+    pub(crate) skip: bool,
+}
+
+/// The patch struct of the container, it's only used in crate or container properties.
+#[derive(Clone)]
+pub(crate) struct PatchConf {
+    pub(crate) vis: VisibilityConf,
+    pub(crate) name: Option<syn::Ident>,
 }
 
 #[derive(Clone)]
 pub(crate) struct OrdFieldConf {
     pub(crate) number: Option<usize>,
+    /// The span of the serial number, to report errors for the same serial numbers.
+    pub(crate) number_span: Option<proc_macro2::Span>,
     pub(crate) sort_type: SortTypeConf,
+    /// Implement `Eq` and `Ord` too, it's only used in crate or container properties.
+    pub(crate) full: bool,
+    /// Order all fields in the declaration order, it's only used in crate or container properties.
+    pub(crate) all: bool,
+    /// Exclude the field when the `all` is set.
+    pub(crate) skip: bool,
+    /// The function to extract the key from the field, the key is compared instead of the field.
+    pub(crate) by: Option<syn::Path>,
+    /// Compare the lengths of the collections instead of the contents.
+    pub(crate) by_len: ExtraOptionConf,
+    /// Compare the floating-point numbers via `total_cmp`.
+    pub(crate) total: ExtraOptionConf,
+    /// The span is kept to report errors for non-optional fields.
+    pub(crate) none_order: Option<(NoneOrderConf, proc_macro2::Span)>,
 }
 
 #[derive(Clone)]
@@ -137,8 +481,103 @@ pub(crate) struct FieldConf {
     pub(crate) set: SetFieldConf,
     pub(crate) mut_: MutFieldConf,
     pub(crate) clr: ClrFieldConf,
+    pub(crate) into: IntoFieldConf,
+    pub(crate) push: OptInFieldConf,
+    pub(crate) push_str: OptInFieldConf,
+    pub(crate) push_char: OptInFieldConf,
+    pub(crate) pop: OptInFieldConf,
+    pub(crate) insert: OptInFieldConf,
+    pub(crate) remove: OptInFieldConf,
+    pub(crate) extend: OptInFieldConf,
+    pub(crate) append: OptInFieldConf,
+    pub(crate) reserve: OptInFieldConf,
+    pub(crate) into_iter: OptInFieldConf,
+    pub(crate) drain: OptInFieldConf,
+    /// Generate the `_range` variant of `drain`.
+    pub(crate) drain_range: ExtraOptionConf,
+    pub(crate) take: OptInFieldConf,
+    pub(crate) has: OptInFieldConf,
+    pub(crate) get_or_insert: OptInFieldConf,
+    /// Generate the `_with` variant of `get_or_insert`, which takes a closure.
+    pub(crate) get_or_insert_lazy: bool,
+    pub(crate) take_default: OptInFieldConf,
+    pub(crate) with: OptInFieldConf,
+    pub(crate) update: OptInFieldConf,
+    pub(crate) map: OptInFieldConf,
+    pub(crate) toggle: OptInFieldConf,
+    pub(crate) add: OptInFieldConf,
+    pub(crate) sub: OptInFieldConf,
+    pub(crate) checked_add: OptInFieldConf,
+    pub(crate) saturating_add: OptInFieldConf,
+    pub(crate) swap: OptInFieldConf,
+    pub(crate) iter_mut: OptInFieldConf,
+    pub(crate) keys: OptInFieldConf,
+    pub(crate) values: OptInFieldConf,
+    pub(crate) values_mut: OptInFieldConf,
+    pub(crate) entry: OptInFieldConf,
+    /// The `_len` and `_is_empty` methods, the name is the common part of them.
+    pub(crate) size: OptInFieldConf,
+    pub(crate) contains: OptInFieldConf,
+    pub(crate) retain: OptInFieldConf,
+    pub(crate) truncate: OptInFieldConf,
+    pub(crate) resize: OptInFieldConf,
+    pub(crate) first: OptInFieldConf,
+    pub(crate) last: OptInFieldConf,
+    /// Generate the `_mut` variants of `first` and `last`.
+    pub(crate) first_mut: bool,
+    /// Implement `Deref` to the field, it's only used in field properties.
+    pub(crate) deref: bool,
+    /// Implement `DerefMut` to the field too.
+    pub(crate) deref_mut: bool,
+    pub(crate) as_ref: AsConvConf,
+    pub(crate) as_mut: AsConvConf,
+    pub(crate) borrow: AsConvConf,
+    /// Implement `BorrowMut` too.
+    pub(crate) borrow_mut: bool,
+    /// Implement `IntoIterator` for the container and its references via the field.
+    pub(crate) into_iterator: bool,
+    /// Implement `Extend` for the container via the field, the bare `extend` is the method group.
+    pub(crate) impl_extend: bool,
+    /// Implement `FromIterator` too, the other fields are set to the default values.
+    pub(crate) impl_from_iter: bool,
+    /// Implement `Index` for the container via the field.
+    pub(crate) index: bool,
+    /// Implement `IndexMut` too.
+    pub(crate) index_mut: bool,
+    /// The name of the field in the `fields_const`, it's only used in field properties.
+    pub(crate) reflect_name: Option<String>,
+    pub(crate) last_mut: bool,
+    pub(crate) with_mut: OptInFieldConf,
+    /// Pass `Option<&mut T>` to the closure of `with_mut` for `Option<T>`.
+    pub(crate) with_mut_flatten: ExtraOptionConf,
+    pub(crate) reset: OptInFieldConf,
+    pub(crate) reset_all: ResetAllConf,
+    pub(crate) merge: MergeConf,
     pub(crate) ord: OrdFieldConf,
     pub(crate) skip: bool,
+    /// The builder of the container, it's only used in crate or container properties.
+    pub(crate) builder: VisibilityConf,
+    /// The field is optional in the builder, the default value is used if it's not set.
+    pub(crate) default: bool,
+    /// The value of the field in the generated `Default`, set via `default = "expr"`.
+    pub(crate) default_value: Option<syn::Expr>,
+    /// Implement `Default` for the container, it's only used in crate or container properties.
+    pub(crate) impl_default: bool,
+    pub(crate) debug: DebugConf,
+    /// The template of the generated `Display`, it's only used in container properties.
+    pub(crate) display: Option<syn::LitStr>,
+    pub(crate) patch: PatchConf,
+    pub(crate) new: NewConf,
+    pub(crate) fields_mut: FieldsMutConf,
+    pub(crate) sort_key: SortKeyConf,
+    pub(crate) parts: PartsConf,
+    pub(crate) trait_: TraitConf,
+    pub(crate) fields_const: FieldsConstConf,
+    /// Implement `Hash` via the ordered fields, it's only used in crate or container properties.
+    ///
+    /// The span is kept only if it's set as a container property, since it's an error if there
+    /// is no ordered field in the container.
+    pub(crate) hash: ExtraOptionConf,
 }
 
 impl syn::parse::Parse for CrateConfDef {
@@ -159,27 +598,27 @@ impl CrateConfDef {
     pub(crate) fn set_default_conf(self) {
         let Self { conf } = self;
         let call_count = CALL_COUNT.load(Ordering::SeqCst);
-        unsafe {
-            if CRATE_CONF.is_some() {
-                panic!(
-                    "The default property for the whole crate should be \
-                     set only once for each crate."
-                );
-            } else if call_count > 0 {
+        CRATE_CONF.with(|crate_conf| {
+            if crate_conf.get().is_none() && call_count > 0 {
                 panic!(
                     "Some properties of containers or fields was set \
                      before the default property for the whole crate has been taken effect."
                 );
             }
-            INIT_DEFAULT.call_once(|| {
-                CRATE_CONF = Some(conf);
-            });
-        }
+            if crate_conf.set(conf).is_err() {
+                panic!(
+                    "The default property for the whole crate should be \
+                     set only once for each crate."
+                );
+            }
+        });
     }
 
     fn get_default_conf() -> FieldConf {
         let _ = CALL_COUNT.fetch_add(1, Ordering::SeqCst);
-        unsafe { CRATE_CONF.as_ref().map(ToOwned::to_owned) }.unwrap_or_else(Default::default)
+        CRATE_CONF
+            .with(|crate_conf| crate_conf.get().map(ToOwned::to_owned))
+            .unwrap_or_default()
     }
 }
 
@@ -206,6 +645,21 @@ impl syn::parse::Parse for ContainerDef {
                     Ok(Self {
                         name: ident,
                         generics,
+                        builder: conf.builder,
+                        reset_all: conf.reset_all.clone(),
+                        merge: conf.merge.clone(),
+                        patch: conf.patch.clone(),
+                        new: conf.new.clone(),
+                        fields_mut: conf.fields_mut.clone(),
+                        sort_key: conf.sort_key.clone(),
+                        parts: conf.parts.clone(),
+                        trait_: conf.trait_.clone(),
+                        fields_const: conf.fields_const.clone(),
+                        full_ord: conf.ord.full,
+                        hash: conf.hash,
+                        impl_default: conf.impl_default,
+                        debug: conf.debug.clone(),
+                        display: conf.display.clone(),
                         fields: FieldDef::parse_named_fields(named_fields, conf, ident_span)?,
                     })
                 }
@@ -233,13 +687,25 @@ impl FieldDef {
         span: proc_macro2::Span,
     ) -> ParseResult<Vec<Self>> {
         let mut fields = Vec::new();
-        for f in named_fields.named.into_iter() {
+        for (index, f) in named_fields.named.into_iter().enumerate() {
             let syn::Field {
                 attrs, ident, ty, ..
             } = f.clone();
-            let conf = FieldDef::parse_attrs(f.span(), conf.clone(), &attrs[..])?;
+            let mut conf = FieldDef::parse_attrs(f.span(), conf.clone(), &attrs[..])?;
+            // The fields are ordered in the declaration order if the `all` of `ord` is set.
+            if conf.ord.all && !conf.ord.skip && !conf.skip {
+                conf.ord.number = Some(index);
+            }
             let ident = ident.ok_or_else(|| SynError::new(f.span(), "unreachable"))?;
             let field = Self { ident, ty, conf };
+            if let Some(number) = field.conf.ord.number {
+                if let Some(prev) = fields
+                    .iter()
+                    .find(|f: &&Self| f.conf.ord.number == Some(number))
+                {
+                    return Err(prev.same_serial_number_error(&field));
+                }
+            }
             fields.push(field);
         }
         if fields.is_empty() {
@@ -256,6 +722,62 @@ impl FieldDef {
     ) -> ParseResult<FieldConf> {
         parse_attrs(span, conf, attrs, PropertyType::Field)
     }
+
+    /// The field for the methods in the trait.
+    ///
+    /// The generic setters and the setters which return `Self` are not allowed for trait objects,
+    /// so the setters use the direct form and return nothing by default, and it's an error if
+    /// they were set explicitly.
+    pub(crate) fn for_trait(&self) -> ParseResult<Self> {
+        let mut conf = self.conf.clone();
+        if conf.set.vis.to_ts().is_some() {
+            match conf.set.conversion {
+                SetConversionConf::Into_ => conf.set.conversion = SetConversionConf::Direct,
+                SetConversionConf::TryInto_ => {
+                    return Err(SynError::new(
+                        conf.set.conversion_span.unwrap_or_else(|| self.ident.span()),
+                        "the `fallible` setters are generic, so they could not be used with `trait`",
+                    ));
+                }
+                SetConversionConf::Direct => {}
+            }
+            match conf.set.typ {
+                SetTypeConf::Ref if conf.set.typ_span.is_none() => {
+                    conf.set.typ = SetTypeConf::None_
+                }
+                SetTypeConf::Ref | SetTypeConf::Own => {
+                    return Err(SynError::new(
+                        conf.set.typ_span.unwrap_or_else(|| self.ident.span()),
+                        "the setters which return `Self` could not be used with `trait`, \
+                         set the `type` to `none` or `replace`",
+                    ));
+                }
+                SetTypeConf::None_ | SetTypeConf::Replace => {}
+            }
+        }
+        Ok(Self {
+            ident: self.ident.clone(),
+            ty: self.ty.clone(),
+            conf,
+        })
+    }
+
+    /// The error for two fields which have the same serial number, it points to the other one.
+    pub(crate) fn same_serial_number_error(&self, other: &Self) -> SynError {
+        SynError::new(
+            other
+                .conf
+                .ord
+                .number_span
+                .unwrap_or_else(|| other.ident.span()),
+            format!(
+                "the fields `{}` and `{}` have the same serial number `_{}`",
+                self.ident.unraw(),
+                other.ident.unraw(),
+                other.conf.ord.number.unwrap_or_default(),
+            ),
+        )
+    }
 }
 
 impl GetTypeConf {
@@ -269,12 +791,184 @@ impl GetTypeConf {
             Some("ref") => Some(GetTypeConf::Ref),
             Some("copy") => Some(GetTypeConf::Copy_),
             Some("clone") => Some(GetTypeConf::Clone_),
+            Some("try_ref") => Some(GetTypeConf::TryRef),
+            Some("bytes") => Some(GetTypeConf::Bytes),
             _ => return Err(SynError::new(span, "unreachable result")),
         };
         Ok(choice)
     }
 }
 
+impl GetWithConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let func_opt = namevalue_params.get("with");
+        let returns_opt = namevalue_params.get("returns");
+        match (func_opt, returns_opt) {
+            (Some(func), Some(returns)) => {
+                if syn::parse_str::<syn::Path>(func).is_err() {
+                    Err(SynError::new(
+                        span,
+                        format!("failed to parse `{}` as a path", func),
+                    ))
+                } else if syn::parse_str::<syn::Type>(returns).is_err() {
+                    Err(SynError::new(
+                        span,
+                        format!("failed to parse `{}` as a type", returns),
+                    ))
+                } else {
+                    Ok(Some(Self {
+                        func: func.to_owned(),
+                        returns: returns.to_owned(),
+                    }))
+                }
+            }
+            (Some(_), None) => Err(SynError::new(
+                span,
+                "the return type should be set via `returns` if `with` was set",
+            )),
+            (None, Some(_)) => Err(SynError::new(
+                span,
+                "the function should be set via `with` if `returns` was set",
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    pub(crate) fn to_ts(&self) -> (syn::Path, syn::Type) {
+        let func = syn::parse_str(&self.func).expect("the path has been checked");
+        let returns = syn::parse_str(&self.returns).expect("the type has been checked");
+        (func, returns)
+    }
+}
+
+impl SetValidatorConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let func_opt = namevalue_params.get("validator");
+        let error_opt = namevalue_params.get("error");
+        match (func_opt, error_opt) {
+            (Some(func), Some(error)) => {
+                if syn::parse_str::<syn::Path>(func).is_err() {
+                    Err(SynError::new(
+                        span,
+                        format!("failed to parse `{}` as a path", func),
+                    ))
+                } else if syn::parse_str::<syn::Type>(error).is_err() {
+                    Err(SynError::new(
+                        span,
+                        format!("failed to parse `{}` as a type", error),
+                    ))
+                } else {
+                    Ok(Some(Self {
+                        func: func.to_owned(),
+                        error: error.to_owned(),
+                    }))
+                }
+            }
+            (Some(_), None) => Err(SynError::new(
+                span,
+                "the error type should be set via `error` if `validator` was set",
+            )),
+            (None, Some(_)) => Err(SynError::new(
+                span,
+                "the function should be set via `validator` if `error` was set",
+            )),
+            (None, None) => Ok(None),
+        }
+    }
+
+    pub(crate) fn to_ts(&self) -> (syn::Path, syn::Type) {
+        let func = syn::parse_str(&self.func).expect("the path has been checked");
+        let error = syn::parse_str(&self.error).expect("the type has been checked");
+        (func, error)
+    }
+}
+
+impl SetRangeConf {
+    pub(crate) fn parse_from_input(
+        namevalue_params: &::std::collections::HashMap<&str, String>,
+        clamp: bool,
+        span: proc_macro2::Span,
+        prop_type: PropertyType,
+    ) -> ParseResult<Option<Self>> {
+        let min = namevalue_params.get("min").map(ToOwned::to_owned);
+        let max = namevalue_params.get("max").map(ToOwned::to_owned);
+        for bound in min.iter().chain(max.iter()) {
+            let is_number = match syn::parse_str::<syn::Lit>(bound.trim_start_matches('-')) {
+                Ok(syn::Lit::Int(ref lit)) => lit.suffix().is_empty(),
+                Ok(syn::Lit::Float(ref lit)) => lit.suffix().is_empty(),
+                _ => false,
+            };
+            if !is_number {
+                return Err(SynError::new(
+                    span,
+                    format!("`{}` should be a number literal without suffix", bound),
+                ));
+            }
+        }
+        if min.is_none() && max.is_none() {
+            if clamp {
+                Err(SynError::new(
+                    span,
+                    "the `clamp` should be set with `min` or `max`",
+                ))
+            } else {
+                Ok(None)
+            }
+        } else {
+            let enable =
+                ExtraOptionConf::Enable(Some(span).filter(|_| prop_type == PropertyType::Field));
+            Ok(Some(Self {
+                min,
+                max,
+                clamp,
+                enable,
+            }))
+        }
+    }
+
+    /// Returns the bounds with the type suffix, and whether the value should be clamped.
+    pub(crate) fn to_ts(
+        &self,
+        number_type: &syn::Type,
+    ) -> (
+        Option<proc_macro2::TokenStream>,
+        Option<proc_macro2::TokenStream>,
+        bool,
+    ) {
+        let suffix = if let syn::Type::Path(type_path) = number_type {
+            type_path
+                .path
+                .segments
+                .last()
+                .map(|seg| seg.ident.to_string())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+        let to_ts = |bound: &String| {
+            let (neg, number) = if let Some(number) = bound.strip_prefix('-') {
+                (quote!(-), number)
+            } else {
+                (quote!(), bound.as_str())
+            };
+            let lit: syn::Lit = syn::parse_str(&format!("{}{}", number, suffix))
+                .expect("the number has been checked");
+            quote!(#neg #lit)
+        };
+        (
+            self.min.as_ref().map(to_ts),
+            self.max.as_ref().map(to_ts),
+            self.clamp,
+        )
+    }
+}
+
 impl SetTypeConf {
     pub(crate) fn parse_from_input(
         namevalue_params: &::std::collections::HashMap<&str, String>,
@@ -332,6 +1026,29 @@ impl VisibilityConf {
             VisibilityConf::Private => Some(quote!()),
         }
     }
+
+    // Opt-in methods are enabled with the default visibility if no visibility was set.
+    pub(crate) fn enable(self) -> Self {
+        match self {
+            VisibilityConf::Disable => VisibilityConf::Crate,
+            _ => self,
+        }
+    }
+}
+
+impl SetConversionConf {
+    pub(crate) fn parse_from_input(
+        input: Option<&str>,
+        span: proc_macro2::Span,
+    ) -> ParseResult<Option<Self>> {
+        let choice = match input {
+            None => None,
+            Some("fallible") => Some(SetConversionConf::TryInto_),
+            Some("direct") => Some(SetConversionConf::Direct),
+            _ => return Err(SynError::new(span, "unreachable result")),
+        };
+        Ok(choice)
+    }
 }
 
 impl SortTypeConf {
@@ -356,6 +1073,86 @@ impl SortTypeConf {
     }
 }
 
+impl ExtraOptionConf {
+    pub(crate) fn parse_from_input(
+        input: Option<&str>,
+        span: proc_macro2::Span,
+        prop_type: PropertyType,
+    ) -> Option<Self> {
+        input.map(|_| {
+            if prop_type == PropertyType::Field {
+                ExtraOptionConf::Enable(Some(span))
+            } else {
+                ExtraOptionConf::Enable(None)
+            }
+        })
+    }
+
+    /// Checks if the option should be applied to a field.
+    ///
+    /// The input is the data which is required by the option, it's `None` if the field is
+    /// unsupported.
+    pub(crate) fn check<T>(self, input: Option<T>, msg: &str) -> ParseResult<Option<T>> {
+        match (self, input) {
+            (ExtraOptionConf::Disable, _) => Ok(None),
+            (ExtraOptionConf::Enable(_), Some(data)) => Ok(Some(data)),
+            (ExtraOptionConf::Enable(None), None) => Ok(None),
+            (ExtraOptionConf::Enable(Some(span)), None) => Err(SynError::new(span, msg)),
+        }
+    }
+}
+
+impl MethodAttrsConf {
+    pub(crate) fn parse_from_input(input: Option<&syn::MetaList>) -> ParseResult<Option<Self>> {
+        let list = if let Some(list) = input {
+            list
+        } else {
+            return Ok(None);
+        };
+        let mut attrs = Vec::new();
+        for nested_meta in list.nested.iter() {
+            if let syn::NestedMeta::Lit(syn::Lit::Str(content)) = nested_meta {
+                let text = content.value();
+                let parsed = syn::parse::Parser::parse_str(
+                    syn::Attribute::parse_outer,
+                    &format!("#[{}]", text),
+                );
+                match parsed {
+                    Ok(ref parsed_attrs) if parsed_attrs.len() == 1 => attrs.push(text),
+                    _ => {
+                        return Err(SynError::new(
+                            content.span(),
+                            format!("failed to parse `{}` as an attribute", text),
+                        ));
+                    }
+                }
+            } else {
+                return Err(SynError::new(
+                    nested_meta.span(),
+                    "this attribute should be a string literal",
+                ));
+            }
+        }
+        if attrs.is_empty() {
+            Err(SynError::new(
+                list.span(),
+                "this attribute should not be empty",
+            ))
+        } else {
+            Ok(Some(MethodAttrsConf(attrs)))
+        }
+    }
+
+    pub(crate) fn to_ts(&self) -> proc_macro2::TokenStream {
+        let attrs = self.0.iter().map(|text| {
+            let tokens: proc_macro2::TokenStream =
+                text.parse().expect("the attribute has been checked");
+            quote!(#[#tokens])
+        });
+        quote!(#(#attrs)*)
+    }
+}
+
 impl MethodNameConf {
     pub(crate) fn parse_from_input(
         namevalue_params: &::std::collections::HashMap<&str, String>,
@@ -364,7 +1161,22 @@ impl MethodNameConf {
         let name_opt = namevalue_params.get("name").map(ToOwned::to_owned);
         let prefix_opt = namevalue_params.get("prefix").map(ToOwned::to_owned);
         let suffix_opt = namevalue_params.get("suffix").map(ToOwned::to_owned);
-        if let Some(name) = name_opt {
+        let template_opt = namevalue_params.get("template").map(ToOwned::to_owned);
+        if let Some(template) = template_opt {
+            if name_opt.is_some() || prefix_opt.is_some() || suffix_opt.is_some() {
+                Err(SynError::new(
+                    span,
+                    "do not set name, prefix or suffix if template was set",
+                ))
+            } else if !template.contains("{}") {
+                Err(SynError::new(
+                    span,
+                    "the template should contain a `{}` as the placeholder of the field name",
+                ))
+            } else {
+                Ok(Some(MethodNameConf::Template(template)))
+            }
+        } else if let Some(name) = name_opt {
             if prefix_opt.is_some() || suffix_opt.is_some() {
                 Err(SynError::new(
                     span,
@@ -390,14 +1202,38 @@ impl MethodNameConf {
         }
     }
 
-    pub(crate) fn complete(&self, field_name: &syn::Ident) -> syn::Ident {
+    pub(crate) fn suffix(suffix: &str) -> Self {
+        MethodNameConf::Format {
+            prefix: "".to_owned(),
+            suffix: suffix.to_owned(),
+        }
+    }
+
+    pub(crate) fn complete(&self, field_name: &syn::Ident) -> ParseResult<syn::Ident> {
+        // Strip the `r#` of raw identifiers, then add it back only if the method name is a keyword.
+        let field_name_str = field_name.unraw().to_string();
         let method_name = match self {
             MethodNameConf::Name(ref name) => name.to_owned(),
             MethodNameConf::Format { prefix, suffix } => {
-                format!("{}{}{}", prefix, field_name.to_string(), suffix)
+                format!("{}{}{}", prefix, field_name_str, suffix)
             }
+            MethodNameConf::Template(template) => template.replace("{}", &field_name_str),
         };
-        syn::Ident::new(&method_name, field_name.span())
+        if syn::parse_str::<syn::Ident>(&method_name).is_ok() {
+            Ok(syn::Ident::new(&method_name, field_name.span()))
+        } else if syn::parse::Parser::parse_str(syn::Ident::parse_any, &method_name).is_ok()
+            && !["_", "crate", "self", "Self", "super"].contains(&method_name.as_str())
+        {
+            Ok(syn::Ident::new_raw(&method_name, field_name.span()))
+        } else {
+            Err(SynError::new(
+                field_name.span(),
+                format!(
+                    "the method name `{}` for the field `{}` is not a valid identifier",
+                    method_name, field_name_str
+                ),
+            ))
+        }
     }
 }
 
@@ -405,17 +1241,17 @@ impl OrdFieldConf {
     pub(crate) fn parse_from_path_params<'a>(
         path_params: &::std::collections::HashSet<&syn::Path>,
         options: &[&'a str],
-        span: proc_macro2::Span,
         prop_type: PropertyType,
-    ) -> ParseResult<(Option<&'a str>, Option<usize>)> {
+    ) -> ParseResult<(Option<&'a str>, Option<usize>, Option<proc_macro2::Span>)> {
         let mut sort_type = None;
         let mut number_opt = None;
+        let mut number_span_opt = None;
         for p in path_params.iter() {
             let s = p
                 .get_ident()
                 .ok_or_else(|| SynError::new(p.span(), "this attribute should be a single ident"))?
                 .to_string();
-            if options.iter().any(|opt| *opt == s.as_str()) {
+            if options.contains(&s.as_str()) {
                 if sort_type.is_some() {
                     return Err(SynError::new(
                         p.span(),
@@ -442,6 +1278,7 @@ impl OrdFieldConf {
                         ));
                     }
                     number_opt = Some(n);
+                    number_span_opt = Some(p.span());
                 } else {
                     return Err(SynError::new(
                         p.span(),
@@ -452,11 +1289,7 @@ impl OrdFieldConf {
                 return Err(SynError::new(p.span(), "this attribute was unknown"));
             }
         }
-        if prop_type == PropertyType::Field && number_opt.is_none() {
-            Err(SynError::new(span, "no serial number was set"))
-        } else {
-            Ok((sort_type, number_opt))
-        }
+        Ok((sort_type, number_opt, number_span_opt))
     }
 }
 
@@ -470,6 +1303,13 @@ impl ::std::default::Default for FieldConf {
                     suffix: "".to_owned(),
                 },
                 typ: GetTypeConf::Auto,
+                indexed: ExtraOptionConf::Disable,
+                slice_range: ExtraOptionConf::Disable,
+                raw_option: ExtraOptionConf::Disable,
+                no_coerce: false,
+                with: None,
+                error: None,
+                attrs: MethodAttrsConf::default(),
             },
             set: SetFieldConf {
                 vis: VisibilityConf::Crate,
@@ -478,7 +1318,26 @@ impl ::std::default::Default for FieldConf {
                     suffix: "".to_owned(),
                 },
                 typ: SetTypeConf::Ref,
+                typ_span: None,
                 full_option: false,
+                strip_option: ExtraOptionConf::Disable,
+                conversion: SetConversionConf::Into_,
+                conversion_span: None,
+                validator: None,
+                range: None,
+                clone_from: false,
+                if_some: false,
+                vec: ExtraOptionConf::Disable,
+                fmt: ExtraOptionConf::Disable,
+                transform: None,
+                per_element: ExtraOptionConf::Disable,
+                wrap: ExtraOptionConf::Disable,
+                locked: ExtraOptionConf::Disable,
+                try_lock: false,
+                track_change: false,
+                on_change: None,
+                allow_unused: false,
+                attrs: MethodAttrsConf::default(),
             },
             mut_: MutFieldConf {
                 vis: VisibilityConf::Crate,
@@ -486,6 +1345,12 @@ impl ::std::default::Default for FieldConf {
                     prefix: "mut_".to_owned(),
                     suffix: "".to_owned(),
                 },
+                indexed: ExtraOptionConf::Disable,
+                raw: false,
+                slice: ExtraOptionConf::Disable,
+                inner: ExtraOptionConf::Disable,
+                or_default: ExtraOptionConf::Disable,
+                attrs: MethodAttrsConf::default(),
             },
             clr: ClrFieldConf {
                 vis: VisibilityConf::Crate,
@@ -494,29 +1359,381 @@ impl ::std::default::Default for FieldConf {
                     suffix: "".to_owned(),
                 },
                 scope: ClrScopeConf::Option_,
+                chain: ExtraOptionConf::Disable,
+                explicit: ExtraOptionConf::Disable,
+            },
+            into: IntoFieldConf {
+                vis: VisibilityConf::Disable,
+                name: MethodNameConf::Format {
+                    prefix: "into_".to_owned(),
+                    suffix: "".to_owned(),
+                },
+            },
+            push: OptInFieldConf::new("push_"),
+            push_str: OptInFieldConf::new("push_str_"),
+            push_char: OptInFieldConf::new("push_char_"),
+            pop: OptInFieldConf::new("pop_"),
+            insert: OptInFieldConf::new("insert_"),
+            remove: OptInFieldConf::new("remove_"),
+            extend: OptInFieldConf::new("extend_"),
+            append: OptInFieldConf::new("append_"),
+            reserve: OptInFieldConf::new("reserve_"),
+            into_iter: OptInFieldConf {
+                name: MethodNameConf::Format {
+                    prefix: "into_".to_owned(),
+                    suffix: "_iter".to_owned(),
+                },
+                ..OptInFieldConf::new("")
+            },
+            drain: OptInFieldConf::new("drain_"),
+            drain_range: ExtraOptionConf::Disable,
+            take: OptInFieldConf::new("take_"),
+            has: OptInFieldConf::new("has_"),
+            get_or_insert: OptInFieldConf::new("get_or_insert_"),
+            get_or_insert_lazy: false,
+            take_default: OptInFieldConf::new("take_"),
+            with: OptInFieldConf::new("with_"),
+            update: OptInFieldConf::new("update_"),
+            map: OptInFieldConf::new("map_"),
+            toggle: OptInFieldConf::new("toggle_"),
+            add: OptInFieldConf::new("add_"),
+            sub: OptInFieldConf::new("sub_"),
+            checked_add: OptInFieldConf::new("checked_add_"),
+            saturating_add: OptInFieldConf::new("saturating_add_"),
+            swap: OptInFieldConf::new("swap_"),
+            iter_mut: OptInFieldConf::new("iter_mut_"),
+            keys: OptInFieldConf::with_suffix("_keys"),
+            values: OptInFieldConf::with_suffix("_values"),
+            values_mut: OptInFieldConf::with_suffix("_values_mut"),
+            entry: OptInFieldConf::new("entry_"),
+            size: OptInFieldConf::with_suffix(""),
+            contains: OptInFieldConf::new("contains_"),
+            retain: OptInFieldConf::new("retain_"),
+            truncate: OptInFieldConf::new("truncate_"),
+            resize: OptInFieldConf::new("resize_"),
+            first: OptInFieldConf::new("first_"),
+            last: OptInFieldConf::new("last_"),
+            first_mut: false,
+            deref: false,
+            deref_mut: false,
+            as_ref: AsConvConf::default(),
+            as_mut: AsConvConf::default(),
+            borrow: AsConvConf::default(),
+            borrow_mut: false,
+            into_iterator: false,
+            impl_extend: false,
+            impl_from_iter: false,
+            index: false,
+            index_mut: false,
+            reflect_name: None,
+            last_mut: false,
+            with_mut: OptInFieldConf {
+                name: MethodNameConf::Format {
+                    prefix: "with_".to_owned(),
+                    suffix: "_mut".to_owned(),
+                },
+                ..OptInFieldConf::new("")
+            },
+            with_mut_flatten: ExtraOptionConf::Disable,
+            reset: OptInFieldConf::new("reset_"),
+            reset_all: ResetAllConf {
+                vis: VisibilityConf::Disable,
+                name: syn::Ident::new("reset_all", proc_macro2::Span::call_site()),
+                skip: false,
+            },
+            merge: MergeConf {
+                vis: VisibilityConf::Disable,
+                name: None,
+                options: false,
+                overwrite: false,
+                skip: false,
             },
             ord: OrdFieldConf {
                 number: None,
+                number_span: None,
                 sort_type: SortTypeConf::Ascending,
+                full: false,
+                all: false,
+                skip: false,
+                by: None,
+                by_len: ExtraOptionConf::Disable,
+                total: ExtraOptionConf::Disable,
+                none_order: None,
             },
             skip: false,
+            builder: VisibilityConf::Disable,
+            patch: PatchConf {
+                vis: VisibilityConf::Disable,
+                name: None,
+            },
+            new: NewConf {
+                vis: VisibilityConf::Disable,
+                name: syn::Ident::new("new", proc_macro2::Span::call_site()),
+                is_const: false,
+                optional_defaults: false,
+                skip: false,
+            },
+            fields_mut: FieldsMutConf {
+                vis: VisibilityConf::Disable,
+                name: syn::Ident::new("fields_mut", proc_macro2::Span::call_site()),
+            },
+            sort_key: SortKeyConf {
+                vis: VisibilityConf::Disable,
+                name: syn::Ident::new("sort_key", proc_macro2::Span::call_site()),
+            },
+            parts: PartsConf {
+                vis: VisibilityConf::Disable,
+            },
+            fields_const: FieldsConstConf {
+                vis: VisibilityConf::Disable,
+                name: syn::Ident::new("FIELDS", proc_macro2::Span::call_site()),
+                method: false,
+            },
+            trait_: TraitConf {
+                vis: VisibilityConf::Disable,
+                name: None,
+                only: false,
+            },
+            hash: ExtraOptionConf::Disable,
+            default_value: None,
+            impl_default: false,
+            debug: DebugConf {
+                enabled: false,
+                redact: false,
+                skip: false,
+                placeholder: "***".to_owned(),
+            },
+            display: None,
+            default: false,
         }
     }
 }
 
-impl FieldConf {
-    fn apply_attrs(&mut self, meta: &syn::Meta, prop_type: PropertyType) -> ParseResult<()> {
-        match meta {
-            syn::Meta::Path(path) => {
-                if path.is_ident(SKIP) {
-                    self.skip = true;
-                } else {
+impl OptInFieldConf {
+    fn new(prefix: &str) -> Self {
+        Self {
+            vis: VisibilityConf::Disable,
+            name: MethodNameConf::Format {
+                prefix: prefix.to_owned(),
+                suffix: "".to_owned(),
+            },
+            explicit: ExtraOptionConf::Disable,
+        }
+    }
+
+    fn with_suffix(suffix: &str) -> Self {
+        Self {
+            name: MethodNameConf::suffix(suffix),
+            ..Self::new("")
+        }
+    }
+}
+
+impl FieldConf {
+    fn opt_in_conf(&mut self, name: &str) -> Option<&mut OptInFieldConf> {
+        match name {
+            "push" => Some(&mut self.push),
+            "push_str" => Some(&mut self.push_str),
+            "push_char" => Some(&mut self.push_char),
+            "pop" => Some(&mut self.pop),
+            "insert" => Some(&mut self.insert),
+            "remove" => Some(&mut self.remove),
+            "extend" => Some(&mut self.extend),
+            "append" => Some(&mut self.append),
+            "reserve" => Some(&mut self.reserve),
+            "into_iter" => Some(&mut self.into_iter),
+            "drain" => Some(&mut self.drain),
+            "take" => Some(&mut self.take),
+            "has" => Some(&mut self.has),
+            "get_or_insert" => Some(&mut self.get_or_insert),
+            "take_default" => Some(&mut self.take_default),
+            "with" => Some(&mut self.with),
+            "update" => Some(&mut self.update),
+            "map" => Some(&mut self.map),
+            "toggle" => Some(&mut self.toggle),
+            "add" => Some(&mut self.add),
+            "sub" => Some(&mut self.sub),
+            "checked_add" => Some(&mut self.checked_add),
+            "saturating_add" => Some(&mut self.saturating_add),
+            "swap" => Some(&mut self.swap),
+            "iter_mut" => Some(&mut self.iter_mut),
+            "keys" => Some(&mut self.keys),
+            "values" => Some(&mut self.values),
+            "values_mut" => Some(&mut self.values_mut),
+            "entry" => Some(&mut self.entry),
+            "size" => Some(&mut self.size),
+            "contains" => Some(&mut self.contains),
+            "retain" => Some(&mut self.retain),
+            "truncate" => Some(&mut self.truncate),
+            "resize" => Some(&mut self.resize),
+            "first" => Some(&mut self.first),
+            "last" => Some(&mut self.last),
+            "with_mut" => Some(&mut self.with_mut),
+            "reset" => Some(&mut self.reset),
+            _ => None,
+        }
+    }
+
+    fn apply_attrs(&mut self, meta: &syn::Meta, prop_type: PropertyType) -> ParseResult<()> {
+        match meta {
+            syn::Meta::Path(path) => {
+                if path.is_ident(SKIP) {
+                    self.skip = true;
+                } else if path.is_ident(INTO) {
+                    self.into.vis = self.into.vis.enable();
+                } else if path.is_ident(BUILDER) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the builder could not be set as a field attribute",
+                        ));
+                    }
+                    self.builder = self.builder.enable();
+                } else if path.is_ident(PATCH) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the patch could not be set as a field attribute",
+                        ));
+                    }
+                    self.patch.vis = self.patch.vis.enable();
+                } else if path.is_ident(NEW) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the constructor could not be set as a field attribute",
+                        ));
+                    }
+                    self.new.vis = self.new.vis.enable();
+                } else if path.is_ident(HASH) {
+                    self.hash = match prop_type {
+                        PropertyType::Field => {
+                            return Err(SynError::new(
+                                path.span(),
+                                "the `hash` could not be set as a field attribute",
+                            ));
+                        }
+                        PropertyType::Container => ExtraOptionConf::Enable(Some(path.span())),
+                        PropertyType::Crate => ExtraOptionConf::Enable(None),
+                    };
+                } else if path.is_ident(DEBUG) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `debug` could not be enabled as a field attribute",
+                        ));
+                    }
+                    self.debug.enabled = true;
+                } else if path.is_ident(IMPL_DEFAULT) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `impl_default` could not be set as a field attribute",
+                        ));
+                    }
+                    self.impl_default = true;
+                } else if path.is_ident(FIELDS_MUT) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `fields_mut` could not be set as a field attribute",
+                        ));
+                    }
+                    self.fields_mut.vis = self.fields_mut.vis.enable();
+                } else if path.is_ident(SORT_KEY) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `sort_key` could not be set as a field attribute",
+                        ));
+                    }
+                    self.sort_key.vis = self.sort_key.vis.enable();
+                } else if path.is_ident(DEREF) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `deref` could only be set as a field attribute",
+                        ));
+                    }
+                    self.deref = true;
+                } else if path.is_ident(AS_REF) || path.is_ident(AS_MUT) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `as_ref` and `as_mut` could only be set as field attributes",
+                        ));
+                    }
+                    if path.is_ident(AS_REF) {
+                        self.as_ref.enabled = true;
+                    } else {
+                        self.as_mut.enabled = true;
+                    }
+                } else if path.is_ident(BORROW) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `borrow` could only be set as a field attribute",
+                        ));
+                    }
+                    self.borrow.enabled = true;
+                } else if path.is_ident(INTO_ITERATOR) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `into_iterator` could only be set as a field attribute",
+                        ));
+                    }
+                    self.into_iterator = true;
+                } else if path.is_ident(IMPL_EXTEND) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `impl_extend` could only be set as a field attribute",
+                        ));
+                    }
+                    self.impl_extend = true;
+                } else if path.is_ident(INDEX) {
+                    if prop_type != PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `index` could only be set as a field attribute",
+                        ));
+                    }
+                    self.index = true;
+                } else if path.is_ident(FIELDS_CONST) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `fields_const` could not be set as a field attribute",
+                        ));
+                    }
+                    self.fields_const.vis = self.fields_const.vis.enable();
+                } else if path.is_ident(PARTS) {
+                    if prop_type == PropertyType::Field {
+                        return Err(SynError::new(
+                            path.span(),
+                            "the `parts` could not be set as a field attribute",
+                        ));
+                    }
+                    self.parts.vis = self.parts.vis.enable();
+                } else if path.is_ident(DEFAULT) {
+                    self.default = true;
+                } else if let Some(conf) = path
+                    .get_ident()
+                    .and_then(|ident| self.opt_in_conf(&ident.to_string()))
+                {
+                    conf.vis = conf.vis.enable();
+                    conf.explicit = ExtraOptionConf::Enable(
+                        Some(path.span()).filter(|_| prop_type == PropertyType::Field),
+                    );
+                } else {
                     return Err(SynError::new(path.span(), "this attribute was unknown"));
                 }
             }
             syn::Meta::List(list) => {
                 let mut path_params = ::std::collections::HashSet::new();
                 let mut namevalue_params = ::std::collections::HashMap::new();
+                let mut list_params = ::std::collections::HashMap::new();
                 for nested_meta in list.nested.iter() {
                     match nested_meta {
                         syn::NestedMeta::Meta(meta) => match meta {
@@ -544,11 +1761,13 @@ impl FieldConf {
                                     ));
                                 }
                             }
-                            _ => {
-                                return Err(SynError::new(
-                                    meta.span(),
-                                    "this attribute should be a path or a name-value pair",
-                                ));
+                            syn::Meta::List(inner_list) => {
+                                if list_params.insert(&inner_list.path, inner_list).is_some() {
+                                    return Err(SynError::new(
+                                        inner_list.path.span(),
+                                        "this attribute has been set twice",
+                                    ));
+                                }
                             }
                         },
                         syn::NestedMeta::Lit(lit) => {
@@ -559,7 +1778,7 @@ impl FieldConf {
                         }
                     }
                 }
-                if path_params.is_empty() && namevalue_params.is_empty() {
+                if path_params.is_empty() && namevalue_params.is_empty() && list_params.is_empty() {
                     return Err(SynError::new(
                         list.span(),
                         "this attribute should not be empty",
@@ -575,11 +1794,30 @@ impl FieldConf {
                     .as_ref()
                 {
                     "get" => {
-                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let paths = check_path_params(
+                            &path_params,
+                            &[
+                                VISIBILITY_OPTIONS,
+                                GET_OPTION_INDEXED,
+                                GET_OPTION_RAW_OPTION,
+                                GET_OPTION_NO_COERCE,
+                                GET_OPTION_SLICE_RANGE,
+                            ],
+                        )?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, GET_TYPE_OPTIONS],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                TEMPLATE_OPTION,
+                                GET_TYPE_OPTIONS,
+                                GET_WITH_OPTION,
+                                GET_RETURNS_OPTION,
+                                GET_ERROR_OPTION,
+                            ],
                         )?;
+                        let lists = check_list_params(&list_params, &[ATTRS_OPTION])?;
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(paths[0], list.path.span())?
                         {
@@ -595,22 +1833,98 @@ impl FieldConf {
                         {
                             self.get.typ = choice;
                         }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[1], list.path.span(), prop_type)
+                        {
+                            self.get.indexed = choice;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[2], list.path.span(), prop_type)
+                        {
+                            self.get.raw_option = choice;
+                        }
+                        if paths[3].is_some() {
+                            self.get.no_coerce = true;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[4], list.path.span(), prop_type)
+                        {
+                            self.get.slice_range = choice;
+                        }
+                        if let Some(choice) =
+                            GetWithConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.get.with = Some(choice);
+                        }
+                        if let Some(error) = namevalues.get("error") {
+                            if syn::parse_str::<syn::Path>(error).is_err() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    format!("failed to parse `{}` as a path", error),
+                                ));
+                            }
+                            self.get.error = Some(error.to_owned());
+                        }
+                        if let Some(choice) = MethodAttrsConf::parse_from_input(lists[0])? {
+                            self.get.attrs = choice;
+                        }
                     }
                     "set" => {
                         let paths = check_path_params(
                             &path_params,
-                            &[VISIBILITY_OPTIONS, SET_OPTION_FULL_OPTION],
+                            &[
+                                VISIBILITY_OPTIONS,
+                                SET_OPTION_OPTION_MODES,
+                                SET_OPTION_CONVERSIONS,
+                                SET_OPTION_CLAMP,
+                                SET_OPTION_CLONE_FROM,
+                                SET_OPTION_IF_SOME,
+                                SET_OPTION_TRACK_CHANGE,
+                                SET_OPTION_ALLOW_UNUSED,
+                                SET_OPTION_VEC,
+                                SET_OPTION_FMT,
+                                SET_OPTION_PER_ELEMENT,
+                                SET_OPTION_WRAP,
+                                SET_OPTION_LOCKED,
+                                SET_OPTION_TRY,
+                            ],
                         )?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, SET_TYPE_OPTIONS],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                TEMPLATE_OPTION,
+                                SET_TYPE_OPTIONS,
+                                SET_VALIDATOR_OPTION,
+                                SET_ERROR_OPTION,
+                                SET_MIN_OPTION,
+                                SET_MAX_OPTION,
+                                SET_ON_CHANGE_OPTION,
+                                SET_TRANSFORM_OPTION,
+                            ],
                         )?;
+                        let lists = check_list_params(&list_params, &[ATTRS_OPTION])?;
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(paths[0], list.path.span())?
                         {
                             self.set.vis = choice;
                         }
-                        self.set.full_option = paths[1].is_some();
+                        self.set.full_option = paths[1] == Some("full_option");
+                        if self.set.full_option {
+                            self.set.strip_option = ExtraOptionConf::Disable;
+                        } else if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[1], list.path.span(), prop_type)
+                        {
+                            self.set.strip_option = choice;
+                        }
+                        if let Some(choice) =
+                            SetConversionConf::parse_from_input(paths[2], list.path.span())?
+                        {
+                            self.set.conversion = choice;
+                            self.set.conversion_span = Some(list.path.span());
+                        }
                         if let Some(choice) =
                             MethodNameConf::parse_from_input(&namevalues, list.path.span())?
                         {
@@ -620,14 +1934,110 @@ impl FieldConf {
                             SetTypeConf::parse_from_input(&namevalues, list.path.span())?
                         {
                             self.set.typ = choice;
+                            self.set.typ_span = Some(list.path.span());
+                        }
+                        if let Some(choice) =
+                            SetValidatorConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.set.validator = Some(choice);
+                        }
+                        if let Some(choice) = SetRangeConf::parse_from_input(
+                            &namevalues,
+                            paths[3].is_some(),
+                            list.path.span(),
+                            prop_type,
+                        )? {
+                            self.set.range = Some(choice);
+                        }
+                        if paths[4].is_some() {
+                            self.set.clone_from = true;
+                        }
+                        if paths[5].is_some() {
+                            self.set.if_some = true;
+                        }
+                        if paths[6].is_some() {
+                            self.set.track_change = true;
+                        }
+                        if paths[7].is_some() {
+                            self.set.allow_unused = true;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[8], list.path.span(), prop_type)
+                        {
+                            self.set.vec = choice;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[9], list.path.span(), prop_type)
+                        {
+                            self.set.fmt = choice;
+                        }
+                        if let Some(transform) = namevalues.get("transform") {
+                            if syn::parse_str::<syn::Path>(transform).is_err() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    format!("failed to parse `{}` as a path", transform),
+                                ));
+                            }
+                            self.set.transform = Some(transform.to_owned());
+                        }
+                        if let Some(choice) = ExtraOptionConf::parse_from_input(
+                            paths[10],
+                            list.path.span(),
+                            prop_type,
+                        ) {
+                            self.set.per_element = choice;
+                        }
+                        if let Some(choice) = ExtraOptionConf::parse_from_input(
+                            paths[11],
+                            list.path.span(),
+                            prop_type,
+                        ) {
+                            self.set.wrap = choice;
+                        }
+                        if let Some(choice) = ExtraOptionConf::parse_from_input(
+                            paths[12],
+                            list.path.span(),
+                            prop_type,
+                        ) {
+                            self.set.locked = choice;
+                        }
+                        if paths[13].is_some() {
+                            self.set.try_lock = true;
+                        }
+                        if let Some(on_change) = namevalues.get("on_change") {
+                            if syn::parse_str::<syn::Path>(on_change).is_err() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    format!("failed to parse `{}` as a path", on_change),
+                                ));
+                            }
+                            self.set.on_change = Some(on_change.to_owned());
+                        }
+                        if let Some(choice) = MethodAttrsConf::parse_from_input(lists[0])? {
+                            self.set.attrs = choice;
                         }
                     }
                     "mut" => {
-                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let paths = check_path_params(
+                            &path_params,
+                            &[
+                                VISIBILITY_OPTIONS,
+                                MUT_OPTION_INDEXED,
+                                MUT_OPTION_RAW,
+                                MUT_OPTION_OR_DEFAULT,
+                            ],
+                        )?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                TEMPLATE_OPTION,
+                                MUT_TYPE_OPTIONS,
+                            ],
                         )?;
+                        let lists = check_list_params(&list_params, &[ATTRS_OPTION])?;
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(paths[0], list.path.span())?
                         {
@@ -638,42 +2048,781 @@ impl FieldConf {
                         {
                             self.mut_.name = choice;
                         }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[1], list.path.span(), prop_type)
+                        {
+                            self.mut_.indexed = choice;
+                        }
+                        if paths[2].is_some() {
+                            self.mut_.raw = true;
+                        }
+                        if let Some(mut_type) = namevalues.get("type") {
+                            let enabled = ExtraOptionConf::parse_from_input(
+                                Some(mut_type),
+                                list.path.span(),
+                                prop_type,
+                            )
+                            .unwrap();
+                            let (slice, inner) = match mut_type.as_str() {
+                                "slice" => (enabled, ExtraOptionConf::Disable),
+                                "inner" => (ExtraOptionConf::Disable, enabled),
+                                _ => (ExtraOptionConf::Disable, ExtraOptionConf::Disable),
+                            };
+                            self.mut_.slice = slice;
+                            self.mut_.inner = inner;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[3], list.path.span(), prop_type)
+                        {
+                            self.mut_.or_default = choice;
+                        }
+                        if let Some(choice) = MethodAttrsConf::parse_from_input(lists[0])? {
+                            self.mut_.attrs = choice;
+                        }
                     }
                     "clr" => {
-                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, CLR_OPTION_CHAIN],
+                        )?;
                         let namevalues = check_namevalue_params(
                             &namevalue_params,
-                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, CLR_TYPE_OPTIONS],
+                            &[
+                                NAME_OPTION,
+                                PREFIX_OPTION,
+                                SUFFIX_OPTION,
+                                TEMPLATE_OPTION,
+                                CLR_TYPE_OPTIONS,
+                            ],
                         )?;
+                        let _ = check_list_params(&list_params, &[])?;
                         if let Some(choice) =
                             VisibilityConf::parse_from_input(paths[0], list.path.span())?
                         {
-                            self.mut_.vis = choice;
+                            self.clr.vis = choice;
                         }
                         if let Some(choice) =
                             MethodNameConf::parse_from_input(&namevalues, list.path.span())?
                         {
-                            self.mut_.name = choice;
+                            self.clr.name = choice;
                         }
                         if let Some(choice) =
                             ClrScopeConf::parse_from_input(&namevalues, list.path.span())?
                         {
                             self.clr.scope = choice;
                         }
+                        if paths[1].is_some() {
+                            self.clr.chain = ExtraOptionConf::Enable(
+                                Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                            );
+                        }
+                        self.clr.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
                     }
-                    "ord" => {
-                        let (sort_type_opt, number_opt) = OrdFieldConf::parse_from_path_params(
+                    "into" => {
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.into.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.into.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.into.name = choice;
+                        }
+                    }
+                    "push" | "pop" | "insert" | "remove" | "extend" | "take" | "with"
+                    | "update" | "map" | "toggle" | "add" | "sub" | "checked_add"
+                    | "saturating_add" | "swap" | "iter_mut" | "keys" | "values" | "values_mut"
+                    | "entry" | "take_default" | "size" | "contains" | "has" | "retain"
+                    | "truncate" | "resize" | "append" | "into_iter" | "push_str" | "push_char"
+                    | "reserve" => {
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        let conf = self
+                            .opt_in_conf(&list.path.get_ident().unwrap().to_string())
+                            .unwrap();
+                        conf.vis = VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                            .unwrap_or_else(|| conf.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            conf.name = choice;
+                        }
+                        conf.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
+                    }
+                    "drain" => {
+                        let paths = check_path_params(
                             &path_params,
-                            SORT_TYPE_OPTIONS,
-                            list.path.span(),
-                            prop_type,
+                            &[VISIBILITY_OPTIONS, DRAIN_OPTION_RANGE],
+                        )?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.drain.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.drain.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.drain.name = choice;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[1], list.path.span(), prop_type)
+                        {
+                            self.drain_range = choice;
+                        }
+                        self.drain.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
+                    }
+                    "get_or_insert" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, GET_OR_INSERT_OPTION_LAZY],
+                        )?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.get_or_insert.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.get_or_insert.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.get_or_insert.name = choice;
+                        }
+                        if paths[1].is_some() {
+                            self.get_or_insert_lazy = true;
+                        }
+                        self.get_or_insert.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
+                    }
+                    "first" | "last" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, ENDS_OPTION_MUTABLE],
+                        )?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        let (conf, mutable) = if list.path.is_ident("first") {
+                            (&mut self.first, &mut self.first_mut)
+                        } else {
+                            (&mut self.last, &mut self.last_mut)
+                        };
+                        conf.vis = VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                            .unwrap_or_else(|| conf.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            conf.name = choice;
+                        }
+                        if paths[1].is_some() {
+                            *mutable = true;
+                        }
+                        conf.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
+                    }
+                    "with_mut" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, WITH_MUT_OPTION_FLATTEN],
                         )?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.with_mut.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.with_mut.vis.enable());
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.with_mut.name = choice;
+                        }
+                        if let Some(choice) =
+                            ExtraOptionConf::parse_from_input(paths[1], list.path.span(), prop_type)
+                        {
+                            self.with_mut_flatten = choice;
+                        }
+                        self.with_mut.explicit = ExtraOptionConf::Enable(
+                            Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                        );
+                    }
+                    "reset" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, RESET_OPTION_SKIP],
+                        )?;
+                        let namevalues = check_namevalue_params(
+                            &namevalue_params,
+                            &[NAME_OPTION, PREFIX_OPTION, SUFFIX_OPTION, TEMPLATE_OPTION],
+                        )?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        if paths[1].is_some() {
+                            if prop_type != PropertyType::Field {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "the `skip` for `reset` could only be set as a field attribute",
+                                ));
+                            }
+                            self.reset_all.skip = true;
+                        }
+                        if let Some(choice) =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                        {
+                            self.reset.vis = choice;
+                        } else if paths[1].is_none() {
+                            self.reset.vis = self.reset.vis.enable();
+                        }
+                        if let Some(choice) =
+                            MethodNameConf::parse_from_input(&namevalues, list.path.span())?
+                        {
+                            self.reset.name = choice;
+                        }
+                        if paths[0].is_some() || paths[1].is_none() {
+                            self.reset.explicit = ExtraOptionConf::Enable(
+                                Some(list.path.span()).filter(|_| prop_type == PropertyType::Field),
+                            );
+                        }
+                    }
+                    "debug" => {
+                        let paths = check_path_params(&path_params, &[DEBUG_OPTION_FIELD])?;
+                        let namevalues =
+                            check_namevalue_params(&namevalue_params, &[PLACEHOLDER_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        if let Some(choice) = paths[0] {
+                            if prop_type != PropertyType::Field {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    format!(
+                                        "the `{}` for `debug` could only be set as a field attribute",
+                                        choice
+                                    ),
+                                ));
+                            }
+                            self.debug.redact = choice == "redact";
+                            self.debug.skip = choice == "skip";
+                        } else if prop_type != PropertyType::Field {
+                            self.debug.enabled = true;
+                        }
+                        if let Some(placeholder) = namevalues.get("placeholder") {
+                            self.debug.placeholder = placeholder.to_owned();
+                        }
+                    }
+                    "reset_all" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `reset_all` could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.reset_all.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.reset_all.vis.enable());
+                        if let Some(name) = namevalues.get("name") {
+                            self.reset_all.name = parse_name(name, list.path.span(), "method")?;
+                        }
+                    }
+                    "fields_mut" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `fields_mut` could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.fields_mut.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.fields_mut.vis.enable());
+                        if let Some(name) = namevalues.get("name") {
+                            self.fields_mut.name = parse_name(name, list.path.span(), "method")?;
+                        }
+                    }
+                    "trait" => {
+                        if prop_type != PropertyType::Container {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `trait` could only be set as a container attribute",
+                            ));
+                        }
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, TRAIT_OPTION_ONLY],
+                        )?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        let name = namevalues.get("name").ok_or_else(|| {
+                            SynError::new(list.path.span(), "the name of the trait is required")
+                        })?;
+                        let name = parse_name(name, list.path.span(), "trait")?;
+                        self.trait_.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.trait_.vis.enable());
+                        self.trait_.name = Some(name);
+                        self.trait_.only = paths[1].is_some();
+                    }
+                    "deref" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `deref` could only be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[DEREF_OPTION_MUT])?;
+                        let _ = check_namevalue_params(&namevalue_params, &[])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.deref = true;
+                        self.deref_mut = paths[0].is_some();
+                    }
+                    "as_ref" | "as_mut" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `as_ref` and `as_mut` could only be set as field attributes",
+                            ));
+                        }
+                        let _ = check_path_params(&path_params, &[])?;
+                        let namevalues =
+                            check_namevalue_params(&namevalue_params, &[TARGET_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        let conf = if list.path.is_ident(AS_REF) {
+                            &mut self.as_ref
+                        } else {
+                            &mut self.as_mut
+                        };
+                        conf.enabled = true;
+                        if let Some(target) = namevalues.get("target") {
+                            let ty = syn::parse_str::<syn::Type>(target).map_err(|_| {
+                                SynError::new(
+                                    list.path.span(),
+                                    format!("`{}` is not a valid type", target),
+                                )
+                            })?;
+                            conf.target = Some(ty);
+                        }
+                    }
+                    "borrow" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `borrow` could only be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[DEREF_OPTION_MUT])?;
+                        let namevalues =
+                            check_namevalue_params(&namevalue_params, &[TARGET_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.borrow.enabled = true;
+                        self.borrow_mut = paths[0].is_some();
+                        if let Some(target) = namevalues.get("target") {
+                            let ty = syn::parse_str::<syn::Type>(target).map_err(|_| {
+                                SynError::new(
+                                    list.path.span(),
+                                    format!("`{}` is not a valid type", target),
+                                )
+                            })?;
+                            self.borrow.target = Some(ty);
+                        }
+                    }
+                    "impl_extend" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `impl_extend` could only be set as a field attribute",
+                            ));
+                        }
+                        let paths =
+                            check_path_params(&path_params, &[IMPL_EXTEND_OPTION_FROM_ITER])?;
+                        let _ = check_namevalue_params(&namevalue_params, &[])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.impl_extend = true;
+                        self.impl_from_iter = paths[0].is_some();
+                    }
+                    "index" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `index` could only be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[DEREF_OPTION_MUT])?;
+                        let _ = check_namevalue_params(&namevalue_params, &[])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.index = true;
+                        self.index_mut = paths[0].is_some();
+                    }
+                    "fields_const" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `fields_const` could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(
+                            &path_params,
+                            &[VISIBILITY_OPTIONS, FIELDS_CONST_OPTION_METHOD],
+                        )?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.fields_const.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.fields_const.vis.enable());
+                        if paths[1].is_some() {
+                            self.fields_const.method = true;
+                        }
+                        if let Some(name) = namevalues.get("name") {
+                            self.fields_const.name =
+                                parse_name(name, list.path.span(), "constant")?;
+                        }
+                    }
+                    "reflect" => {
+                        if prop_type != PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `reflect` could only be set as a field attribute",
+                            ));
+                        }
+                        let _ = check_path_params(&path_params, &[])?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        if let Some(name) = namevalues.get("name") {
+                            self.reflect_name = Some(name.to_owned());
+                        }
+                    }
+                    "parts" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `parts` could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let _ = check_namevalue_params(&namevalue_params, &[])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.parts.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.parts.vis.enable());
+                    }
+                    "sort_key" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the `sort_key` could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.sort_key.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.sort_key.vis.enable());
+                        if let Some(name) = namevalues.get("name") {
+                            self.sort_key.name = parse_name(name, list.path.span(), "method")?;
+                        }
+                    }
+                    "merge" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[
+                                VISIBILITY_OPTIONS,
+                                MERGE_OPTION_SKIP,
+                                MERGE_OPTION_OPTIONS,
+                                MERGE_OPTION_OVERWRITE,
+                            ],
+                        )?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        if prop_type == PropertyType::Field {
+                            if paths[0].is_some()
+                                || !namevalues.is_empty()
+                                || paths[1].is_none()
+                                || paths[2].is_some()
+                                || paths[3].is_some()
+                            {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "only the `skip` of `merge` could be set as a field attribute",
+                                ));
+                            }
+                            self.merge.skip = true;
+                        } else {
+                            if paths[1].is_some() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "the `skip` of `merge` could only be set as a field attribute",
+                                ));
+                            }
+                            self.merge.vis =
+                                VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                    .unwrap_or_else(|| self.merge.vis.enable());
+                            if let Some(name) = namevalues.get("name") {
+                                self.merge.name =
+                                    Some(parse_name(name, list.path.span(), "method")?);
+                            }
+                            if paths[2].is_some() {
+                                self.merge.options = true;
+                            }
+                            if paths[3].is_some() {
+                                if !self.merge.options {
+                                    return Err(SynError::new(
+                                        list.path.span(),
+                                        "the `overwrite` of `merge` requires `options`",
+                                    ));
+                                }
+                                self.merge.overwrite = true;
+                            }
+                        }
+                    }
+                    "builder" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the builder could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let _ = check_namevalue_params(&namevalue_params, &[])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.builder =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.builder.enable());
+                    }
+                    "new" => {
+                        let paths = check_path_params(
+                            &path_params,
+                            &[
+                                VISIBILITY_OPTIONS,
+                                NEW_OPTION_SKIP,
+                                NEW_OPTION_CONST,
+                                NEW_OPTION_OPTIONAL_DEFAULTS,
+                            ],
+                        )?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        if prop_type == PropertyType::Field {
+                            if paths[0].is_some()
+                                || !namevalues.is_empty()
+                                || paths[1].is_none()
+                                || paths[2].is_some()
+                                || paths[3].is_some()
+                            {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "only the `skip` (or `default`) of `new` could be set as a field attribute",
+                                ));
+                            }
+                            self.new.skip = true;
+                        } else {
+                            if paths[1].is_some() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "the `skip` (or `default`) of `new` could only be set as a field attribute",
+                                ));
+                            }
+                            self.new.vis =
+                                VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                    .unwrap_or_else(|| self.new.vis.enable());
+                            if let Some(name) = namevalues.get("name") {
+                                self.new.name = parse_name(name, list.path.span(), "method")?;
+                            }
+                            if paths[2].is_some() {
+                                self.new.is_const = true;
+                            }
+                            if paths[3].is_some() {
+                                self.new.optional_defaults = true;
+                            }
+                        }
+                    }
+                    "patch" => {
+                        if prop_type == PropertyType::Field {
+                            return Err(SynError::new(
+                                list.path.span(),
+                                "the patch could not be set as a field attribute",
+                            ));
+                        }
+                        let paths = check_path_params(&path_params, &[VISIBILITY_OPTIONS])?;
+                        let namevalues = check_namevalue_params(&namevalue_params, &[NAME_OPTION])?;
+                        let _ = check_list_params(&list_params, &[])?;
+                        self.patch.vis =
+                            VisibilityConf::parse_from_input(paths[0], list.path.span())?
+                                .unwrap_or_else(|| self.patch.vis.enable());
+                        if let Some(name) = namevalues.get("name") {
+                            self.patch.name = Some(parse_name(name, list.path.span(), "struct")?);
+                        }
+                    }
+                    "ord" => {
+                        let _ = check_list_params(&list_params, &[])?;
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| path.is_ident(ORD_OPTION_FULL))
+                            .cloned()
+                        {
+                            if prop_type == PropertyType::Field {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the `full` of `ord` could only be set as a crate or container attribute",
+                                ));
+                            }
+                            path_params.remove(path);
+                            self.ord.full = true;
+                        }
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| path.is_ident(ORD_OPTION_ALL))
+                            .cloned()
+                        {
+                            if prop_type == PropertyType::Field {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the `all` of `ord` could only be set as a crate or container attribute",
+                                ));
+                            }
+                            path_params.remove(path);
+                            self.ord.all = true;
+                        }
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| path.is_ident(ORD_OPTION_SKIP))
+                            .cloned()
+                        {
+                            if prop_type != PropertyType::Field || !self.ord.all {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the `skip` of `ord` could only be set as a field attribute when the `all` of `ord` is set",
+                                ));
+                            }
+                            path_params.remove(path);
+                            self.ord.skip = true;
+                        }
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| path.is_ident(ORD_OPTION_BY_LEN))
+                            .cloned()
+                        {
+                            if prop_type != PropertyType::Field {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the `by_len` of `ord` could only be set as a field attribute",
+                                ));
+                            }
+                            path_params.remove(path);
+                            self.ord.by_len = ExtraOptionConf::Enable(Some(path.span()));
+                        }
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| path.is_ident(ORD_OPTION_TOTAL))
+                            .cloned()
+                        {
+                            path_params.remove(path);
+                            self.ord.total = if prop_type == PropertyType::Field {
+                                ExtraOptionConf::Enable(Some(path.span()))
+                            } else {
+                                ExtraOptionConf::Enable(None)
+                            };
+                        }
+                        if let Some(path) = path_params
+                            .iter()
+                            .find(|path| ORD_OPTION_NONE.iter().any(|opt| path.is_ident(opt)))
+                            .cloned()
+                        {
+                            if prop_type != PropertyType::Field {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the order of `None` could only be set as a field attribute",
+                                ));
+                            }
+                            path_params.remove(path);
+                            if path_params
+                                .iter()
+                                .any(|path| ORD_OPTION_NONE.iter().any(|opt| path.is_ident(opt)))
+                            {
+                                return Err(SynError::new(
+                                    path.span(),
+                                    "the `none_first` and `none_last` could not be set together",
+                                ));
+                            }
+                            let choice = if path.is_ident("none_first") {
+                                NoneOrderConf::First
+                            } else {
+                                NoneOrderConf::Last
+                            };
+                            self.ord.none_order = Some((choice, path.span()));
+                        }
+                        for (n, v) in namevalue_params.iter() {
+                            if !n.is_ident(ORD_OPTION_BY) {
+                                return Err(SynError::new(n.span(), "this attribute was unknown"));
+                            }
+                            if prop_type != PropertyType::Field {
+                                return Err(SynError::new(
+                                    n.span(),
+                                    "the `by` of `ord` could only be set as a field attribute",
+                                ));
+                            }
+                            let value = v.value();
+                            let path = syn::parse_str::<syn::Path>(&value).map_err(|err| {
+                                SynError::new(
+                                    v.span(),
+                                    format!("failed to parse `{}` as a path: {}", value, err),
+                                )
+                            })?;
+                            self.ord.by = Some(path);
+                        }
+                        let (sort_type_opt, number_opt, number_span_opt) =
+                            OrdFieldConf::parse_from_path_params(
+                                &path_params,
+                                SORT_TYPE_OPTIONS,
+                                prop_type,
+                            )?;
+                        if prop_type == PropertyType::Field {
+                            if self.ord.all {
+                                if let Some(span) = number_span_opt {
+                                    return Err(SynError::new(
+                                        span,
+                                        "the serial number could not be set since the `all` of `ord` is set",
+                                    ));
+                                }
+                            } else if number_opt.is_none() {
+                                return Err(SynError::new(
+                                    list.path.span(),
+                                    "no serial number was set",
+                                ));
+                            }
+                        }
+                        // The sort type is inherited from the container if it's not set.
                         if let Some(choice) =
                             SortTypeConf::parse_from_input(sort_type_opt, list.path.span())?
                         {
                             self.ord.sort_type = choice;
                         }
                         self.ord.number = number_opt;
+                        self.ord.number_span = number_span_opt;
                     }
                     attr => {
                         return Err(SynError::new(
@@ -684,16 +2833,82 @@ impl FieldConf {
                 }
             }
             syn::Meta::NameValue(name_value) => {
-                return Err(SynError::new(
-                    name_value.span(),
-                    "this attribute should not be a name-value pair",
-                ));
+                if name_value.path.is_ident(DISPLAY) {
+                    if prop_type != PropertyType::Container {
+                        return Err(SynError::new(
+                            name_value.span(),
+                            "the display template could only be set as a container attribute",
+                        ));
+                    }
+                    if let syn::Lit::Str(ref lit) = name_value.lit {
+                        self.display = Some(lit.clone());
+                        return Ok(());
+                    } else {
+                        return Err(SynError::new(
+                            name_value.lit.span(),
+                            "this literal should be a string literal",
+                        ));
+                    }
+                }
+                if name_value.path.is_ident(TRAIT) {
+                    if prop_type != PropertyType::Container {
+                        return Err(SynError::new(
+                            name_value.span(),
+                            "the `trait` could only be set as a container attribute",
+                        ));
+                    }
+                    if let syn::Lit::Str(ref lit) = name_value.lit {
+                        self.trait_.vis = self.trait_.vis.enable();
+                        self.trait_.name = Some(parse_name(&lit.value(), lit.span(), "trait")?);
+                        return Ok(());
+                    } else {
+                        return Err(SynError::new(
+                            name_value.lit.span(),
+                            "this literal should be a string literal",
+                        ));
+                    }
+                }
+                if !name_value.path.is_ident(DEFAULT) {
+                    return Err(SynError::new(
+                        name_value.span(),
+                        "this attribute should not be a name-value pair",
+                    ));
+                }
+                if prop_type != PropertyType::Field {
+                    return Err(SynError::new(
+                        name_value.span(),
+                        "the default value could only be set as a field attribute",
+                    ));
+                }
+                if let syn::Lit::Str(ref lit) = name_value.lit {
+                    let value = lit.value();
+                    let expr = syn::parse_str::<syn::Expr>(&value).map_err(|err| {
+                        SynError::new(
+                            lit.span(),
+                            format!("failed to parse `{}` as an expression: {}", value, err),
+                        )
+                    })?;
+                    self.default_value = Some(expr);
+                } else {
+                    return Err(SynError::new(
+                        name_value.lit.span(),
+                        "this literal should be a string literal",
+                    ));
+                }
             }
         }
         Ok(())
     }
 }
 
+/// Parses the name of a generated item, the raw identifiers (e.g. `r#match`) are supported.
+fn parse_name(name: &str, span: proc_macro2::Span, kind: &str) -> ParseResult<syn::Ident> {
+    let mut ident = syn::parse_str::<syn::Ident>(name)
+        .map_err(|_| SynError::new(span, format!("`{}` is not a valid {} name", name, kind)))?;
+    ident.set_span(span);
+    Ok(ident)
+}
+
 fn check_path_params<'a>(
     path_params: &::std::collections::HashSet<&syn::Path>,
     options: &[&[&'a str]],
@@ -763,6 +2978,21 @@ fn check_namevalue_params<'a>(
     Ok(result)
 }
 
+fn check_list_params<'a>(
+    params: &::std::collections::HashMap<&syn::Path, &'a syn::MetaList>,
+    options: &[&str],
+) -> ParseResult<Vec<Option<&'a syn::MetaList>>> {
+    let mut result = vec![None; options.len()];
+    for (n, v) in params.iter() {
+        if let Some(i) = options.iter().position(|k| n.is_ident(k)) {
+            result[i] = Some(*v);
+        } else {
+            return Err(SynError::new(n.span(), "this attribute was unknown"));
+        }
+    }
+    Ok(result)
+}
+
 fn parse_attrs(
     span: proc_macro2::Span,
     mut conf: FieldConf,
@@ -0,0 +1,17 @@
+use property::Property;
+
+fn small(val: &u8) -> Result<(), &'static str> {
+    if *val < 10 {
+        Ok(())
+    } else {
+        Err("too large")
+    }
+}
+
+#[derive(Property)]
+pub struct Foo {
+    #[property(set(validator = "small", error = "&'static str", clone_from))]
+    a: u8,
+}
+
+fn main() {}
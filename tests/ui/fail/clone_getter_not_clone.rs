@@ -0,0 +1,12 @@
+use property::Property;
+
+pub struct Token;
+
+#[derive(Property)]
+#[property(get(type = "clone"))]
+pub struct Session {
+    id: u64,
+    token: Token,
+}
+
+fn main() {}
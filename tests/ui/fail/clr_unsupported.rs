@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Timer {
+    #[property(clr(crate))]
+    elapsed: std::time::Duration,
+}
+
+fn main() {}
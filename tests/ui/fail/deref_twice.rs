@@ -0,0 +1,11 @@
+use property::Property;
+
+#[derive(Property)]
+struct Foo {
+    #[property(deref)]
+    name: String,
+    #[property(deref)]
+    items: Vec<u8>,
+}
+
+fn main() {}
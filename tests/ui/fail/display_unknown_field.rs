@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(display = "{a} and {b}")]
+struct Foo {
+    a: u8,
+    c: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(reset_all(name = "clear"))]
+struct Pet {
+    #[property(get(name = "clear"))]
+    name: String,
+}
+
+fn main() {}
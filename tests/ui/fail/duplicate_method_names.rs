@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+struct Pet {
+    name: String,
+    #[property(get(name = "name"))]
+    nickname: String,
+}
+
+fn main() {}
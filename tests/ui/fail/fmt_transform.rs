@@ -0,0 +1,13 @@
+use property::Property;
+
+fn trimmed(val: String) -> String {
+    val.trim().to_owned()
+}
+
+#[derive(Property)]
+pub struct Foo {
+    #[property(set(transform = "trimmed", fmt))]
+    title: String,
+}
+
+fn main() {}
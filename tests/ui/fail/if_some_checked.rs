@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Foo {
+    #[property(set(min = "0", max = "5", if_some))]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+struct Foo {
+    #[property(get(disable, indexed))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    #[property(get(prefix = "get-"))]
+    name: String,
+    #[property(set(name = "self"))]
+    age: u8,
+}
+
+fn main() {}
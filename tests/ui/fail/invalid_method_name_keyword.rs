@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Pet {
+    name: String,
+    #[property(set(name = "self"))]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(map)]
+pub struct Connection {
+    host: String,
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(unused_must_use)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(set(type = "own"))]
+pub struct Config {
+    retries: u8,
+}
+
+fn main() {
+    let config = Config::default();
+    config.set_retries(3u8);
+}
@@ -0,0 +1,11 @@
+use property::Property;
+
+pub struct Level(u8);
+
+#[derive(Property)]
+struct Foo {
+    #[property(reset)]
+    level: Level,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Version {
+    #[property(ord(asc, _1))]
+    major: u16,
+    #[property(ord(asc, _1))]
+    minor: u16,
+}
+
+fn main() {}
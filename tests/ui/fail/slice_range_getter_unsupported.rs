@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+struct Foo {
+    #[property(get(disable, slice_range))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use property::Property;
+
+#[derive(Property)]
+pub struct Cache {
+    #[property(take(public), take_default(public))]
+    entry: Option<String>,
+}
+
+fn main() {}
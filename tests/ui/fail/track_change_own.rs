@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(set(type = "own"))]
+pub struct Request {
+    #[property(set(track_change))]
+    path: String,
+}
+
+fn main() {}
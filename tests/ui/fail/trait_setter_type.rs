@@ -0,0 +1,10 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(trait = "HasName")]
+pub struct Foo {
+    #[property(set(type = "ref"))]
+    name: String,
+}
+
+fn main() {}
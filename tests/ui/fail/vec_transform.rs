@@ -0,0 +1,14 @@
+use property::Property;
+
+fn sorted(mut val: Vec<u8>) -> Vec<u8> {
+    val.sort_unstable();
+    val
+}
+
+#[derive(Property)]
+pub struct Foo {
+    #[property(set(transform = "sorted", vec))]
+    items: Vec<u8>,
+}
+
+fn main() {}
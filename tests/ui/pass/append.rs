@@ -0,0 +1,21 @@
+use property::Property;
+
+#[derive(Debug, PartialEq)]
+pub struct Token(u32);
+
+#[derive(Property, Default)]
+#[property(get(disable), push(public), extend(public), append(public))]
+pub struct Tokens {
+    tokens: Vec<Token>,
+}
+
+fn main() {
+    let mut value = Tokens::default();
+    value.push_tokens(Token(0));
+    let mut other = vec![Token(1), Token(2), Token(3)];
+    value.append_tokens(&mut other);
+    assert!(other.is_empty());
+    value.extend_tokens(vec![Token(4)]);
+    assert_eq!(value.tokens.len(), 5);
+    assert_eq!(value.tokens[3], Token(3));
+}
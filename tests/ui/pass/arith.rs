@@ -0,0 +1,16 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(add, sub)]
+struct Foo {
+    total: u64,
+    f: f32,
+    name: String,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.add_total(5).add_total(7).sub_total(2);
+    assert_eq!(f.total, 10);
+    f.add_f(0.5);
+    assert_eq!(f.f, 0.5);
+}
@@ -0,0 +1,44 @@
+use std::path::{Path, PathBuf};
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Entry {
+    #[property(as_ref(target = "str"), as_mut(target = "str"))]
+    name: String,
+    #[property(as_ref(target = "Path"))]
+    path: PathBuf,
+    #[property(as_ref, as_mut)]
+    data: Vec<u8>,
+}
+
+fn name_len(name: impl AsRef<str>) -> usize {
+    name.as_ref().len()
+}
+
+fn file_name(path: impl AsRef<Path>) -> String {
+    path.as_ref()
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn main() {
+    let mut e = Entry {
+        name: "hello".to_owned(),
+        path: PathBuf::from("/tmp/a.txt"),
+        data: vec![1],
+    };
+    assert_eq!(name_len(&e), 5);
+    assert_eq!(file_name(&e), "a.txt");
+    let data: &Vec<u8> = e.as_ref();
+    assert_eq!(data, &vec![1]);
+    let name: &mut str = e.as_mut();
+    name.make_ascii_uppercase();
+    let data: &mut Vec<u8> = e.as_mut();
+    data.push(2);
+    assert_eq!(e.name, "HELLO");
+    assert_eq!(e.data, vec![1, 2]);
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    A,
+}
+#[derive(Property)]
+struct Foo<U: Clone> {
+    #[property(get(type = "copy"))]
+    k: Kind,
+    #[property(get(type = "clone"))]
+    s: String,
+    #[property(get(type = "clone"), set(disable))]
+    u: U,
+}
+fn main() {
+    let f = Foo {
+        k: Kind::A,
+        s: "x".into(),
+        u: vec![1u8],
+    };
+    assert_eq!(f.k(), Kind::A);
+    assert_eq!(f.s(), "x");
+    assert_eq!(f.u(), vec![1u8]);
+}
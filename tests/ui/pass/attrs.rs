@@ -0,0 +1,19 @@
+#![deny(deprecated)]
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(attrs("cfg(all())")))]
+struct Foo {
+    #[property(set(attrs("deprecated(note = \"use set_b\")")))]
+    a: u8,
+    #[property(mut(attrs("cfg(all())", "allow(deprecated)")))]
+    b: u8,
+}
+fn main() {
+    let mut f = Foo { a: 1, b: 2 };
+    #[allow(deprecated)]
+    f.set_a(3);
+    *f.mut_b() += 1;
+    assert_eq!(f.a(), 3);
+    assert_eq!(f.b(), 3);
+}
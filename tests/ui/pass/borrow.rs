@@ -0,0 +1,40 @@
+use std::{borrow::BorrowMut, collections::HashMap};
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full), hash)]
+pub struct Key {
+    #[property(ord(_1), borrow(target = "str"))]
+    name: String,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Buffer {
+    #[property(borrow(mut))]
+    extra: Vec<u8>,
+}
+
+fn main() {
+    let mut map = HashMap::new();
+    map.insert(
+        Key {
+            name: "a".to_owned(),
+        },
+        1,
+    );
+    map.insert(
+        Key {
+            name: "b".to_owned(),
+        },
+        2,
+    );
+    assert_eq!(map.get("a"), Some(&1));
+    assert_eq!(map.get("b"), Some(&2));
+    assert_eq!(map.get("c"), None);
+    let mut k = Buffer { extra: vec![] };
+    let extra: &mut Vec<u8> = k.borrow_mut();
+    extra.push(1);
+    assert_eq!(k.extra, vec![1]);
+}
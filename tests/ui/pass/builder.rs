@@ -0,0 +1,89 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Debug)]
+#[property(builder(public))]
+pub struct Foo {
+    a: u8,
+    name: String,
+    list: Vec<String>,
+    opt: Option<String>,
+    #[property(default)]
+    count: u32,
+    #[property(skip)]
+    cache: Vec<u8>,
+}
+fn main() {
+    let f = Foo::builder()
+        .set_a(1u8)
+        .set_name("x")
+        .set_list(vec!["y"])
+        .set_opt("o")
+        .build()
+        .unwrap();
+    assert_eq!(f.a, 1);
+    assert_eq!(f.opt.as_deref(), Some("o"));
+    assert_eq!(f.count, 0);
+    let err = FooBuilder::default().set_a(1u8).build().unwrap_err();
+    assert_eq!(err, "the field `name` is not set");
+    let f = Foo::builder()
+        .set_a(1u8)
+        .set_name("x")
+        .set_list(Vec::<String>::new())
+        .set_count(5u32)
+        .build()
+        .unwrap();
+    assert_eq!(f.count, 5);
+    assert_eq!(f.opt, None);
+    check_bar();
+}
+fn small(val: &u8) -> Result<(), &'static str> {
+    if *val < 10 {
+        Ok(())
+    } else {
+        Err("too large")
+    }
+}
+#[derive(Property, Debug)]
+#[property(builder)]
+struct Bar {
+    #[property(set(validator = "small", error = "&'static str"))]
+    a: u8,
+    #[property(set(min = "0", max = "5"))]
+    b: u8,
+    #[property(set(max = "5", clamp), default = "3")]
+    c: u8,
+    #[property(set(min = "1"))]
+    d: Option<u8>,
+    #[property(set(disable), default = "\"fixed\".to_owned()")]
+    e: String,
+    #[property(set(direct))]
+    f: u64,
+    #[property(set(strip_option))]
+    g: Option<Vec<String>>,
+}
+fn check_bar() {
+    let bar = || Bar::builder().set_a(1u8).set_b(2u8).set_f(3);
+    let b = bar().build().unwrap();
+    assert_eq!(
+        (b.a, b.b, b.c, b.d, b.e.as_str(), b.f),
+        (1, 2, 3, None, "fixed", 3)
+    );
+    assert_eq!(b.g, None);
+    assert_eq!(
+        bar().set_a(50u8).build().unwrap_err(),
+        "the field `a` is invalid"
+    );
+    assert_eq!(
+        bar().set_b(200u8).build().unwrap_err(),
+        "the field `b` is greater than the maximum"
+    );
+    assert_eq!(bar().set_c(200u8).build().unwrap().c, 5);
+    assert_eq!(
+        bar().set_d(0u8).build().unwrap_err(),
+        "the field `d` is less than the minimum"
+    );
+    assert_eq!(
+        bar().set_g(vec!["x"]).build().unwrap().g,
+        Some(vec!["x".to_owned()])
+    );
+}
@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(type = "bytes"))]
+struct Foo {
+    a: String,
+    b: Vec<u8>,
+}
+fn main() {
+    let f = Foo {
+        a: "ab".into(),
+        b: vec![1],
+    };
+    let a: fn(&Foo) -> &[u8] = Foo::a;
+    assert_eq!(a(&f), b"ab");
+    assert_eq!(f.b(), &[1u8][..]);
+}
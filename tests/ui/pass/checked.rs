@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(checked_add, saturating_add)]
+struct Foo {
+    a: u8,
+    f: f64,
+}
+fn main() {
+    let mut f = Foo { a: 250, f: 0.0 };
+    assert_eq!(f.checked_add_a(5), Some(255));
+    assert_eq!(f.checked_add_a(1), None);
+    assert_eq!(f.a, 255);
+    f.a = 250;
+    f.saturating_add_a(10).saturating_add_a(1);
+    assert_eq!(f.a, 255);
+}
@@ -0,0 +1,18 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(clone_from))]
+struct Foo {
+    name: String,
+    list: Vec<u8>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.name.reserve(100);
+    let cap = f.name.capacity();
+    let src = String::from("hello");
+    f.set_name_from(&src).set_list_from(&vec![1, 2]);
+    assert_eq!(f.name, "hello");
+    assert_eq!(f.name.capacity(), cap);
+    assert_eq!(f.list, vec![1, 2]);
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::HashMap;
+#[derive(Property, Default)]
+#[property(clr(chain, scope = "auto", prefix = "reset_"))]
+struct Foo {
+    a: Option<u8>,
+    b: Vec<u8>,
+    c: HashMap<u8, u8>,
+    #[property(clr(disable))]
+    d: Vec<u8>,
+    #[property(clr(scope = "all"))]
+    e: std::time::Duration,
+    #[property(mut(disable))]
+    f: Option<u8>,
+}
+fn main() {
+    let mut f = Foo {
+        a: Some(1),
+        b: vec![1],
+        d: vec![2],
+        f: Some(3),
+        ..Default::default()
+    };
+    f.c.insert(1, 1);
+    f.reset_a().reset_b().reset_c().reset_e().reset_f();
+    assert_eq!(f.a, None);
+    assert!(f.b.is_empty() && f.c.is_empty());
+    assert_eq!(f.d, vec![2]);
+    assert_eq!(f.mut_b().len(), 0);
+}
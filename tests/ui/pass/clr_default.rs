@@ -0,0 +1,22 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(clr(scope = "auto"))]
+struct Timer {
+    laps: Vec<u32>,
+    label: Option<String>,
+    // Without a known clear strategy, no method is generated.
+    elapsed: std::time::Duration,
+}
+
+fn main() {
+    let mut timer = Timer {
+        laps: vec![1, 2],
+        label: Some("run".to_owned()),
+        ..Default::default()
+    };
+    let () = timer.clear_laps();
+    let () = timer.clear_label();
+    assert!(timer.laps.is_empty());
+    assert_eq!(timer.label, None);
+}
@@ -0,0 +1,34 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), contains(public))]
+pub struct Lookup {
+    names: Vec<String>,
+    queue: VecDeque<u32>,
+    arr: [u8; 2],
+    map: HashMap<String, u32>,
+    set: BTreeSet<String>,
+    text: String,
+}
+
+fn main() {
+    let mut value = Lookup {
+        names: vec!["a".to_owned()],
+        queue: vec![1].into(),
+        arr: [1, 2],
+        text: "hello".to_owned(),
+        ..Default::default()
+    };
+    value.map.insert("k".to_owned(), 1);
+    value.set.insert("s".to_owned());
+    assert!(value.contains_names("a"));
+    assert!(!value.contains_names("b"));
+    assert!(value.contains_queue(&1));
+    assert!(value.contains_arr(&2));
+    assert!(value.contains_map("k"));
+    assert!(!value.contains_map("x"));
+    assert!(value.contains_set("s"));
+    assert!(value.contains_text("ell"));
+}
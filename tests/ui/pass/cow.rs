@@ -0,0 +1,38 @@
+use property::Property;
+use std::borrow::Cow;
+#[derive(Property)]
+struct Foo {
+    name: Cow<'static, str>,
+    #[property(set(type = "replace"))]
+    data: Cow<'static, [u8]>,
+}
+#[derive(Property)]
+#[property(get(public), set(public))]
+struct Bar<'a> {
+    name: Cow<'a, str>,
+    #[property(set(type = "replace"))]
+    path: std::borrow::Cow<'a, std::path::Path>,
+}
+fn main() {
+    let mut f = Foo {
+        name: Cow::Borrowed(""),
+        data: Cow::Borrowed(&[]),
+    };
+    f.set_name("lit");
+    assert!(matches!(f.name, Cow::Borrowed("lit")));
+    f.set_name(String::from("owned"));
+    assert!(matches!(f.name, Cow::Owned(ref s) if s == "owned"));
+    assert!(f.set_data(vec![1u8]).is_empty());
+    let old = f.set_data(&[2u8][..]);
+    assert_eq!(&*old, &[1u8][..]);
+    let s = String::from("local");
+    let mut b = Bar {
+        name: Cow::Borrowed(""),
+        path: Cow::Borrowed(std::path::Path::new("/")),
+    };
+    b.set_name(&s[..]);
+    assert_eq!(b.name(), "local");
+    let old = b.set_path(std::path::PathBuf::from("/tmp"));
+    assert_eq!(&*old, std::path::Path::new("/"));
+    assert_eq!(b.path(), std::path::Path::new("/tmp"));
+}
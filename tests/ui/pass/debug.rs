@@ -0,0 +1,57 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), debug)]
+pub struct Account {
+    id: u32,
+    #[property(debug(redact))]
+    password: String,
+    #[property(debug(redact, placeholder = "<hidden>"))]
+    token: String,
+    #[property(debug(skip))]
+    cache: Vec<u8>,
+    #[property(skip)]
+    r#type: u8,
+}
+
+#[derive(Property)]
+#[property(debug(placeholder = "-"))]
+pub struct Wrapper<Item> {
+    #[property(debug(redact))]
+    items: Vec<Item>,
+    count: usize,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), debug)]
+pub struct Pair<Item> {
+    first: Item,
+    second: Item,
+}
+
+fn main() {
+    let a = Account {
+        id: 1,
+        password: "secret".to_owned(),
+        token: "abc".to_owned(),
+        cache: vec![1],
+        r#type: 2,
+    };
+    assert_eq!(
+        format!("{:?}", a),
+        r#"Account { id: 1, password: ***, token: <hidden>, type: 2 }"#
+    );
+    let _ = (&a.cache, &a.password, &a.token);
+    let w = Wrapper::<u8> {
+        items: vec![1],
+        count: 1,
+    };
+    assert_eq!(format!("{:?}", w), "Wrapper { items: -, count: 1 }");
+    let _ = &w.items;
+    let p = Pair {
+        first: "a",
+        second: "b",
+    };
+    assert_eq!(format!("{:?}", p), r#"Pair { first: "a", second: "b" }"#);
+    let _ = (p.first, p.second);
+}
@@ -0,0 +1,34 @@
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Tagged {
+    #[property(deref)]
+    name: String,
+    #[property(get(public))]
+    tag: u8,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Stack<Item> {
+    #[property(deref(mut))]
+    items: Vec<Item>,
+}
+
+fn main() {
+    let t = Tagged {
+        name: "Hello".to_owned(),
+        tag: 1,
+    };
+    assert_eq!(t.len(), 5);
+    assert!(t.starts_with("He"));
+    assert_eq!(t.tag(), 1);
+    let name: &String = &t;
+    assert_eq!(name, "Hello");
+    let mut s = Stack { items: Vec::new() };
+    s.push(1u8);
+    s.push(2);
+    assert_eq!(s.pop(), Some(2));
+    assert_eq!(s.items, vec![1]);
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(direct))]
+struct Foo {
+    a: u8,
+    b: Vec<String>,
+    #[property(set(strip_option, type = "replace"))]
+    c: Option<f64>,
+    #[property(set(type = "own"))]
+    d: Option<Vec<u8>>,
+    #[property(set(type = "none", min = "1"))]
+    e: i32,
+}
+trait Setter {
+    fn set_a(&mut self, val: u8) -> &mut Self;
+}
+impl Setter for Foo {
+    fn set_a(&mut self, val: u8) -> &mut Self {
+        Foo::set_a(self, val)
+    }
+}
+fn main() {
+    let mut f = Foo::default();
+    let _: fn(&mut Foo, u8) -> &mut Foo = Foo::set_a;
+    let _: fn(&mut Foo, Vec<String>) -> &mut Foo = Foo::set_b;
+    let _: fn(&mut Foo, f64) -> Option<f64> = Foo::set_c;
+    let _: fn(&mut Foo, Option<f64>) -> Option<f64> = Foo::set_c_opt;
+    let _: fn(Foo, Vec<u8>) -> Foo = Foo::set_d;
+    let _: fn(&mut Foo, i32) -> Result<(), &'static str> = Foo::set_e;
+    f.set_a(1).set_b(vec![]);
+    assert_eq!(f.set_c(1.0), None);
+    assert!(f.set_e(0).is_err());
+    let f = f.set_d(vec![1]);
+    assert_eq!(f.d, Some(vec![1]));
+}
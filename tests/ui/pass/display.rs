@@ -0,0 +1,39 @@
+use std::fmt;
+
+use property::Property;
+
+#[derive(Debug)]
+pub enum Status {
+    Active,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+#[property(display = "{id}: {name} ({status:?}) {{{type:>3}}} #{id}")]
+pub struct User {
+    id: u32,
+    name: String,
+    status: Status,
+    r#type: u8,
+}
+
+#[derive(Property)]
+#[property(display = "[{items:?}]")]
+pub struct Wrapper<Item: fmt::Debug> {
+    items: Vec<Item>,
+}
+
+fn main() {
+    let u = User {
+        id: 7,
+        name: "alice".to_owned(),
+        status: Status::Active,
+        r#type: 1,
+    };
+    assert_eq!(u.to_string(), "7: alice (Active) {  1} #7");
+    let w = Wrapper {
+        items: vec![1u8, 2],
+    };
+    assert_eq!(w.to_string(), "[[1, 2]]");
+    let _ = &w.items;
+}
@@ -0,0 +1,35 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), drain(public))]
+pub struct Queues {
+    #[property(drain(range))]
+    items: Vec<u32>,
+    #[property(drain(range))]
+    queue: VecDeque<u32>,
+    map: BTreeMap<String, u32>,
+    set: HashSet<u8>,
+}
+
+fn main() {
+    let mut value = Queues {
+        items: vec![1, 2, 3, 4],
+        queue: vec![5, 6].into(),
+        ..Default::default()
+    };
+    value.map.insert("a".to_owned(), 1);
+    value.set.insert(1);
+    assert_eq!(value.drain_items_range(1..3), vec![2, 3]);
+    assert_eq!(value.items, vec![1, 4]);
+    assert_eq!(value.drain_items(), vec![1, 4]);
+    assert!(value.items.is_empty());
+    assert_eq!(value.drain_queue_range(..1), vec![5]);
+    assert_eq!(value.drain_queue(), vec![6]);
+    assert!(value.queue.is_empty());
+    assert_eq!(value.drain_map(), vec![("a".to_owned(), 1)]);
+    assert!(value.map.is_empty());
+    assert_eq!(value.drain_set(), vec![1]);
+    assert!(value.set.is_empty());
+}
@@ -0,0 +1,22 @@
+use std::collections::{BTreeMap, HashMap};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(mut(disable), entry(public))]
+pub struct Cache {
+    hashed: HashMap<String, Vec<u32>>,
+    sorted: BTreeMap<u32, String>,
+}
+
+fn main() {
+    let mut value = Cache::default();
+    value.entry_hashed("a").or_insert_with(Vec::new).push(1);
+    value.entry_hashed("a").or_insert_with(Vec::new).push(2);
+    value
+        .entry_sorted(3u32)
+        .or_insert_with(|| "x".to_owned())
+        .push('y');
+    assert_eq!(value.hashed["a"], vec![1, 2]);
+    assert_eq!(value.sorted[&3], "xy");
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::{BTreeMap, HashMap, HashSet};
+#[derive(Property, Default)]
+#[property(extend)]
+struct Foo {
+    a: Vec<String>,
+    b: HashMap<String, u32>,
+    c: HashSet<u32>,
+    d: String,
+    e: BTreeMap<u8, u8>,
+    x: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.extend_a(["a", "b"].iter().copied())
+        .extend_a(vec![String::from("c")]);
+    assert_eq!(f.a, vec!["a", "b", "c"]);
+    f.extend_b(vec![("x", 1u8), ("y", 2u8)]);
+    assert_eq!(f.b.get("y"), Some(&2));
+    f.extend_c(vec![1u16, 2]);
+    f.extend_d(vec!["ab", "cd"])
+        .extend_d(vec![String::from("e")]);
+    assert_eq!(f.d, "abcde");
+    f.extend_e(vec![(1u8, 2u8)]);
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+use property::Property;
+use std::convert::TryFrom;
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+struct Port(u16);
+impl TryFrom<u32> for Port {
+    type Error = String;
+    fn try_from(v: u32) -> Result<Self, String> {
+        if v > 0 && v < 65536 {
+            Ok(Port(v as u16))
+        } else {
+            Err(format!("bad {}", v))
+        }
+    }
+}
+#[derive(Property, Default)]
+#[property(set(fallible))]
+struct Foo {
+    #[property(get(type = "copy"))]
+    port: Port,
+    ports: Vec<Port>,
+    #[property(set(type = "replace"))]
+    opt: Option<Port>,
+    #[property(set(type = "own"))]
+    small: u8,
+    #[property(set(type = "none", strip_option))]
+    list: Option<Vec<Port>>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_port(80u32).unwrap().set_port(81u32).unwrap();
+    assert_eq!(f.port(), Port(81));
+    assert_eq!(f.set_port(0u32).err(), Some("bad 0".to_owned()));
+    assert_eq!(f.port(), Port(81));
+    f.set_ports(vec![1u32, 2]).unwrap();
+    assert!(f.set_ports(vec![1u32, 0, 70000]).is_err());
+    assert_eq!(f.ports(), &[Port(1), Port(2)]);
+    assert_eq!(f.set_opt(5u32), Ok(None));
+    assert_eq!(f.set_opt(6u32), Ok(Some(Port(5))));
+    assert!(f.set_list(vec![3u32]).is_ok());
+    assert!(f.set_list(vec![0u32]).is_err());
+    assert_eq!(f.list, Some(vec![Port(3)]));
+    let f = f.set_small(300u32).err().is_some();
+    assert!(f);
+}
@@ -0,0 +1,43 @@
+#![allow(non_upper_case_globals)]
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), fields_const(public))]
+pub struct User {
+    id: u32,
+    #[property(reflect(name = "user_name"))]
+    name: String,
+    #[property(skip)]
+    cache: u8,
+    r#type: u8,
+}
+
+#[derive(Property)]
+#[property(fields_const(name = "COLUMNS", method))]
+pub struct Row<Item> {
+    value: Item,
+}
+
+#[derive(Property)]
+#[property(fields_const(name = "r#const"))]
+pub struct Raw {
+    value: u8,
+}
+
+fn main() {
+    assert_eq!(User::FIELDS, &["id", "user_name", "type"]);
+    assert!(!User::FIELDS.contains(&"cache"));
+    assert_eq!(Row::<u8>::COLUMNS, &["value"]);
+    assert_eq!(Row::<u8>::field_names(), &["value"]);
+    let u = User {
+        id: 1,
+        name: String::new(),
+        cache: 0,
+        r#type: 0,
+    };
+    let _ = (u.id, u.name, u.cache, u.r#type);
+    let _ = Row { value: 1u8 }.value;
+    assert_eq!(Raw::r#const, &["value"]);
+    let _ = Raw { value: 0 }.value;
+}
@@ -0,0 +1,43 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(fields_mut(public))]
+pub struct Pair {
+    total: u32,
+    items: Vec<u32>,
+    #[property(skip)]
+    hidden: bool,
+}
+
+#[derive(Property, Default)]
+#[property(fields_mut(name = "split"))]
+pub struct Single {
+    only: u8,
+}
+
+#[derive(Property, Default)]
+#[property(fields_mut(name = "r#ref"))]
+pub struct Raw {
+    only: u8,
+}
+
+fn main() {
+    let mut value = Pair {
+        items: vec![1, 2, 3],
+        ..Default::default()
+    };
+    let (total, items) = value.fields_mut();
+    *total = items.iter().sum();
+    items.push(*total);
+    assert_eq!(value.total, 6);
+    assert_eq!(value.items, vec![1, 2, 3, 6]);
+    assert!(!value.hidden);
+    let mut single = Single::default();
+    let (only,) = single.split();
+    *only = 1;
+    assert_eq!(single.only, 1);
+
+    let mut raw = Raw::default();
+    *raw.r#ref().0 = 3;
+    assert_eq!(raw.only, 3);
+}
@@ -0,0 +1,32 @@
+use std::collections::VecDeque;
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), first(public), last(public, mutable))]
+pub struct History {
+    log: Vec<u32>,
+    queue: VecDeque<String>,
+    #[property(first(disable), last(disable))]
+    other: Vec<u32>,
+    count: u32,
+}
+
+fn main() {
+    let mut value = History::default();
+    assert_eq!(value.first_log(), None);
+    assert_eq!(value.last_log(), None);
+    assert_eq!(value.last_log_mut(), None);
+    value.log = vec![1, 2, 3];
+    value.queue.push_back("a".to_owned());
+    value.queue.push_back("b".to_owned());
+    assert_eq!(value.first_log(), Some(&1));
+    assert_eq!(value.last_log(), Some(&3));
+    *value.last_log_mut().unwrap() = 4;
+    assert_eq!(value.log, vec![1, 2, 4]);
+    assert_eq!(value.first_queue().map(String::as_str), Some("a"));
+    value.last_queue_mut().unwrap().push('c');
+    assert_eq!(value.last_queue().map(String::as_str), Some("bc"));
+    assert!(value.other.is_empty());
+    assert_eq!(value.count, 0);
+}
@@ -0,0 +1,20 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), get_or_insert(public, lazy))]
+pub struct Lazy {
+    cache: Option<Vec<u32>>,
+    name: Option<String>,
+    count: u32,
+}
+
+fn main() {
+    let mut value = Lazy::default();
+    value.get_or_insert_cache(vec![1]).push(2);
+    assert_eq!(value.get_or_insert_cache(vec![9]), &mut vec![1, 2]);
+    assert_eq!(value.get_or_insert_name("a"), "a");
+    assert_eq!(value.get_or_insert_name_with(|| unreachable!()), "a");
+    value.name = None;
+    assert_eq!(value.get_or_insert_name_with(|| "b".to_owned()), "b");
+    assert_eq!(value.count, 0);
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+use property::Property;
+mod util {
+    pub fn trimmed(s: &str) -> &str {
+        s.trim()
+    }
+    pub fn doubled(n: &u32) -> u64 {
+        u64::from(*n) * 2
+    }
+}
+#[derive(Property)]
+struct Foo {
+    #[property(get(with = "util::trimmed", returns = "&str"))]
+    a: String,
+    #[property(get(public, name = "twice", with = "crate::util::doubled", returns = "u64"))]
+    b: u32,
+}
+fn main() {
+    let f = Foo {
+        a: "  x ".into(),
+        b: 21,
+    };
+    assert_eq!(f.a(), "x");
+    assert_eq!(f.twice(), 42);
+}
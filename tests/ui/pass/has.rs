@@ -0,0 +1,30 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), has(public))]
+pub struct Profile {
+    name: Option<String>,
+    #[property(has(prefix = "is_", suffix = "_set"))]
+    age: Option<u8>,
+    #[property(has(disable))]
+    email: Option<String>,
+    count: u32,
+}
+
+impl Profile {
+    fn has_email(&self) -> &'static str {
+        "custom"
+    }
+}
+
+fn main() {
+    let value = Profile {
+        name: Some("a".to_owned()),
+        ..Default::default()
+    };
+    assert!(value.has_name());
+    assert!(!value.is_age_set());
+    assert_eq!(value.has_email(), "custom");
+    assert!(value.email.is_none());
+    assert_eq!(value.count, 0);
+}
@@ -0,0 +1,103 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use property::Property;
+
+#[derive(Property, Debug)]
+#[property(get(disable), set(disable), mut(disable), hash)]
+pub struct Key {
+    #[property(ord(_1))]
+    name: String,
+    #[property(ord(_0))]
+    id: u32,
+    cache: Vec<u8>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full), hash)]
+pub struct FullKey {
+    #[property(ord(_0))]
+    id: u32,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full), hash)]
+pub struct Measure {
+    #[property(ord(_0, total))]
+    value: f64,
+    #[property(ord(_1))]
+    unit: &'static str,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), hash)]
+pub struct Tagged<T> {
+    #[property(ord(_0))]
+    tag: T,
+    note: String,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let a = Key {
+        name: "a".to_owned(),
+        id: 1,
+        cache: vec![1],
+    };
+    let b = Key {
+        name: "a".to_owned(),
+        id: 1,
+        cache: vec![2],
+    };
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    let mut map = HashMap::new();
+    map.insert(FullKey { id: 1 }, "x");
+    assert_eq!(map.get(&FullKey { id: 1 }), Some(&"x"));
+    assert!(!a.cache.is_empty() && !b.cache.is_empty());
+    assert_eq!(a.name, b.name);
+    assert_eq!(a.id, b.id);
+
+    let nan = Measure {
+        value: f64::NAN,
+        unit: "m",
+    };
+    let same_nan = Measure {
+        value: f64::NAN,
+        unit: "m",
+    };
+    assert!(nan == same_nan);
+    assert_eq!(hash_of(&nan), hash_of(&same_nan));
+    let zero = Measure {
+        value: 0.0,
+        unit: "m",
+    };
+    let neg_zero = Measure {
+        value: -0.0,
+        unit: "m",
+    };
+    assert!(zero != neg_zero);
+    let mut measures = HashMap::new();
+    measures.insert(nan, 1);
+    assert_eq!(measures.get(&same_nan), Some(&1));
+    assert_eq!(hash_of(&zero), hash_of(&zero));
+    assert_eq!(neg_zero.unit, zero.unit);
+
+    let x = Tagged {
+        tag: 7u8,
+        note: "x".to_owned(),
+    };
+    let y = Tagged {
+        tag: 7u8,
+        note: "y".to_owned(),
+    };
+    assert!(x == y);
+    assert_eq!(hash_of(&x), hash_of(&y));
+    assert_ne!(x.note, y.note);
+}
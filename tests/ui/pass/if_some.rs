@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(if_some))]
+struct Foo {
+    name: String,
+    list: Vec<String>,
+    opt: Option<u8>,
+    #[property(set(direct))]
+    d: u8,
+    #[property(set(direct, strip_option))]
+    e: Option<u8>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_name_if_some(Some("a")).set_name_if_some(None::<&str>);
+    assert_eq!(f.name, "a");
+    f.set_list_if_some(Some(vec!["x"]));
+    f.set_opt_if_some(Some(1u8)).set_opt_if_some(None::<u8>);
+    assert_eq!(f.opt, Some(1));
+    f.set_d_if_some(Some(2)).set_e_if_some(Some(3));
+    assert_eq!((f.d, f.e), (2, Some(3)));
+}
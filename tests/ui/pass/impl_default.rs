@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use property::Property;
+
+#[derive(Property, Debug)]
+#[property(get(disable), set(disable), mut(disable), impl_default)]
+pub struct Config {
+    #[property(default = "Duration::from_secs(30)")]
+    timeout: Duration,
+    #[property(default = r#""https".to_owned()"#)]
+    scheme: String,
+    #[property(default = "Self::PORT")]
+    port: u16,
+    retries: u32,
+    #[property(skip)]
+    tags: Vec<String>,
+}
+
+impl Config {
+    const PORT: u16 = 8080;
+}
+
+#[derive(Property)]
+#[property(impl_default)]
+pub struct Wrapper<Item> {
+    #[property(default = "Vec::with_capacity(1)")]
+    items: Vec<Item>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), impl_default)]
+pub struct Pair<Item> {
+    first: Item,
+    #[property(default = "1")]
+    second: u8,
+}
+
+fn main() {
+    let value = Config::default();
+    assert_eq!(value.timeout, Duration::from_secs(30));
+    assert_eq!(value.scheme, "https");
+    assert_eq!(value.port, 8080);
+    assert_eq!(value.retries, 0);
+    assert!(value.tags.is_empty());
+    let wrapper = Wrapper::<u8>::default();
+    assert!(wrapper.items.is_empty());
+    let pair = Pair::<String>::default();
+    assert!(pair.first.is_empty());
+    assert_eq!(pair.second, 1);
+}
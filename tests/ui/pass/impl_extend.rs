@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Playlist {
+    title: String,
+    #[property(impl_extend(from_iter))]
+    tracks: Vec<String>,
+}
+
+#[derive(Property, Default)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Index {
+    #[property(impl_extend)]
+    map: HashMap<String, u8>,
+}
+
+fn main() {
+    let mut p: Playlist = vec!["a".to_owned()].into_iter().collect();
+    assert_eq!(p.title, "");
+    p.extend(vec!["b".to_owned(), "c".to_owned()]);
+    assert_eq!(p.tracks, vec!["a", "b", "c"]);
+    let mut i = Index::default();
+    i.extend(vec![("x".to_owned(), 1)]);
+    assert_eq!(i.map.get("x"), Some(&1));
+}
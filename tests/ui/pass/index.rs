@@ -0,0 +1,58 @@
+use std::collections::{BTreeMap, HashMap};
+use std::panic::{self, AssertUnwindSafe};
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Playlist {
+    #[property(index(mut))]
+    tracks: Vec<String>,
+}
+
+#[derive(Property)]
+#[property(set(disable), mut(disable))]
+pub struct Config {
+    #[property(index(mut))]
+    values: HashMap<String, u8>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Sorted<Item> {
+    #[property(index)]
+    values: BTreeMap<String, Item>,
+}
+
+fn main() {
+    let mut p = Playlist {
+        tracks: vec!["a".to_owned(), "b".to_owned()],
+    };
+    assert_eq!(p[1], "b");
+    p[0].push('!');
+    assert_eq!(p[0], "a!");
+    let mut c = Config {
+        values: vec![("key".to_owned(), 1)].into_iter().collect(),
+    };
+    assert_eq!(c["key"], 1);
+    c["key"] += 1;
+    assert_eq!(c["key"], 2);
+    let s = Sorted {
+        values: vec![("k".to_owned(), 'x')].into_iter().collect(),
+    };
+    assert_eq!(s["k"], 'x');
+    // A missing key panics as the map does, the getter could be used to look it up instead.
+    panic::set_hook(Box::new(|_| {}));
+    let mut empty = Config {
+        values: HashMap::new(),
+    };
+    assert!(panic::catch_unwind(|| empty["none"]).is_err());
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| empty["none"] = 1)).unwrap_err();
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied());
+    assert_eq!(message, Some("no entry found for key"));
+    assert_eq!(empty.values().get("none"), None);
+    assert_eq!(c.values().get("key"), Some(&2));
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::VecDeque;
+#[derive(Property)]
+#[property(get(indexed), mut(indexed))]
+struct Foo {
+    a: Vec<String>,
+    b: [u8; 4],
+    c: VecDeque<u32>,
+    d: String,
+}
+fn main() {
+    let mut f = Foo {
+        a: vec!["x".into()],
+        b: [1, 2, 3, 4],
+        c: VecDeque::new(),
+        d: "x".into(),
+    };
+    assert_eq!(f.a_at(0).map(String::as_str), Some("x"));
+    assert_eq!(f.a_at(1), None);
+    *f.mut_b_at(2).unwrap() = 9;
+    assert_eq!(f.b_at(2), Some(&9));
+    assert!(f.mut_c_at(0).is_none());
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+#[derive(Property, Default)]
+#[property(insert, remove)]
+struct Foo {
+    a: HashMap<String, u32>,
+    b: BTreeMap<String, String>,
+    c: HashSet<String>,
+    d: BTreeSet<u8>,
+    e: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    assert_eq!(f.insert_a("x", 1u8), None);
+    assert_eq!(f.insert_a("x", 2u32), Some(1));
+    assert_eq!(f.remove_a("x"), Some(2));
+    assert_eq!(f.remove_a("x"), None);
+    f.insert_b("k", "v");
+    assert_eq!(f.remove_b("k").as_deref(), Some("v"));
+    assert!(f.insert_c("s"));
+    assert!(!f.insert_c("s"));
+    assert!(f.remove_c("s"));
+    f.insert_d(1u8);
+    assert!(f.remove_d(&1));
+}
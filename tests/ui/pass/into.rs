@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Debug, PartialEq)]
+struct NoClone(u32);
+#[derive(Property)]
+#[property(into)]
+struct Foo {
+    a: NoClone,
+    #[property(into(public, name = "take_b"))]
+    b: String,
+    #[property(into(disable))]
+    c: u8,
+}
+fn main() {
+    let f = Foo {
+        a: NoClone(1),
+        b: "x".into(),
+        c: 1,
+    };
+    assert_eq!(f.into_a(), NoClone(1));
+    let f = Foo {
+        a: NoClone(1),
+        b: "x".into(),
+        c: 1,
+    };
+    assert_eq!(f.take_b(), "x");
+}
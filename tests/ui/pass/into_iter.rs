@@ -0,0 +1,37 @@
+use std::collections::BTreeMap;
+
+use property::Property;
+
+#[derive(Debug, PartialEq)]
+pub struct Token(u32);
+
+#[derive(Property, Default)]
+#[property(get(disable), into, into_iter(public))]
+pub struct Parts {
+    tokens: Vec<Token>,
+    map: BTreeMap<u8, Token>,
+    #[property(into(disable), into_iter(prefix = "into_", suffix = ""))]
+    others: Vec<Token>,
+}
+
+fn main() {
+    let value = Parts {
+        tokens: vec![Token(1), Token(2)],
+        ..Default::default()
+    };
+    let tokens: Vec<Token> = value.into_tokens_iter().collect();
+    assert_eq!(tokens, vec![Token(1), Token(2)]);
+    let mut value = Parts::default();
+    value.map.insert(1, Token(3));
+    assert_eq!(
+        value.into_map_iter().collect::<Vec<_>>(),
+        vec![(1, Token(3))]
+    );
+    let value = Parts {
+        others: vec![Token(4)],
+        ..Default::default()
+    };
+    assert_eq!(value.into_others().next(), Some(Token(4)));
+    let value = Parts::default();
+    assert!(value.into_tokens().is_empty());
+}
@@ -0,0 +1,56 @@
+use std::collections::{BTreeMap, HashSet};
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Playlist {
+    title: String,
+    #[property(into_iterator)]
+    tracks: Vec<String>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Scores<'a> {
+    #[property(into_iterator)]
+    scores: BTreeMap<&'a str, u8>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Tags {
+    #[property(into_iterator)]
+    tags: HashSet<u8>,
+}
+
+fn main() {
+    let mut p = Playlist {
+        title: "t".to_owned(),
+        tracks: vec!["a".to_owned(), "b".to_owned()],
+    };
+    let mut names = Vec::new();
+    for t in &p {
+        names.push(t.clone());
+    }
+    assert_eq!(names, vec!["a", "b"]);
+    for t in &mut p {
+        t.push('!');
+    }
+    let _ = &p.title;
+    let owned: Vec<String> = p.into_iter().collect();
+    assert_eq!(owned, vec!["a!", "b!"]);
+    let mut s = Scores {
+        scores: vec![("x", 1), ("y", 2)].into_iter().collect(),
+    };
+    for (_, v) in &mut s {
+        *v += 1;
+    }
+    let pairs: Vec<_> = (&s).into_iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(pairs, vec![("x", 2), ("y", 3)]);
+    let t = Tags {
+        tags: vec![1].into_iter().collect(),
+    };
+    assert_eq!((&t).into_iter().count(), 1);
+    assert_eq!(t.into_iter().collect::<Vec<_>>(), vec![1]);
+}
@@ -0,0 +1,39 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(mut(disable), iter_mut(public))]
+pub struct Counter {
+    items: Vec<u32>,
+    queue: VecDeque<u32>,
+    arr: [u8; 2],
+    #[property(iter_mut(name = "scores"))]
+    map: BTreeMap<String, u32>,
+    #[property(iter_mut(disable))]
+    other: Vec<u32>,
+}
+
+fn main() {
+    let mut value = Counter {
+        items: vec![1, 2, 3],
+        queue: vec![4, 5].into(),
+        ..Default::default()
+    };
+    value.map.insert("a".to_owned(), 1);
+    value.map.insert("b".to_owned(), 2);
+    value.iter_mut_items().for_each(|x| *x *= 10);
+    value.iter_mut_queue().for_each(|x| *x += 1);
+    value.iter_mut_arr().for_each(|x| *x = 7);
+    for (key, score) in value.scores() {
+        if key == "b" {
+            *score += 100;
+        }
+    }
+    assert_eq!(value.items, vec![10, 20, 30]);
+    assert_eq!(value.queue, vec![5, 6]);
+    assert_eq!(value.arr, [7, 7]);
+    assert_eq!(value.map["a"], 1);
+    assert_eq!(value.map["b"], 102);
+    assert!(value.other.is_empty());
+}
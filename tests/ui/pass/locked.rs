@@ -0,0 +1,67 @@
+use property::Property;
+use std::sync::{Arc, Mutex, RwLock};
+#[derive(Property)]
+#[property(get(disable), set(locked))]
+struct State {
+    count: Mutex<u64>,
+    #[property(set(type = "replace"))]
+    name: RwLock<String>,
+    #[property(set(try, type = "none"))]
+    flag: Mutex<bool>,
+    #[property(set(disable), with(public))]
+    plain: Mutex<u8>,
+}
+fn main() {
+    let state = Arc::new(State {
+        count: Mutex::new(0),
+        name: RwLock::new(String::new()),
+        flag: Mutex::new(false),
+        plain: Mutex::new(0),
+    });
+    let handles: Vec<_> = (0..4u64)
+        .map(|i| {
+            let state = Arc::clone(&state);
+            std::thread::spawn(move || {
+                state.set_count(i).set_count(1u32);
+                state.set_flag(true).unwrap();
+            })
+        })
+        .collect();
+    for h in handles {
+        h.join().unwrap();
+    }
+    assert!(*state.count.lock().unwrap() >= 1);
+    assert!(*state.flag.lock().unwrap());
+    assert_eq!(state.set_name("a"), "");
+    assert_eq!(state.set_name("b"), "a");
+    let poisoned = Arc::new(State {
+        count: Mutex::new(0),
+        name: RwLock::new(String::new()),
+        flag: Mutex::new(false),
+        plain: Mutex::new(0),
+    });
+    std::panic::set_hook(Box::new(|_| {}));
+    let p = Arc::clone(&poisoned);
+    let _ = std::thread::spawn(move || {
+        let _guard = p.flag.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    assert!(poisoned.set_flag(true).is_err());
+    let p = Arc::clone(&poisoned);
+    let _ = std::thread::spawn(move || {
+        let _guard = p.count.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    poisoned.set_count(5u32);
+    assert_eq!(*poisoned.count.lock().unwrap_err().into_inner(), 5);
+    let s = State {
+        count: Mutex::new(0),
+        name: RwLock::new(String::new()),
+        flag: Mutex::new(false),
+        plain: Mutex::new(0),
+    };
+    let s = s.with_plain(Mutex::new(3));
+    assert_eq!(*s.plain.lock().unwrap(), 3);
+}
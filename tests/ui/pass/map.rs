@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+use property::Property;
+struct NoDefault(Vec<String>);
+#[derive(Property)]
+#[property(map, with)]
+struct Foo {
+    headers: Vec<String>,
+    nd: NoDefault,
+}
+fn main() {
+    let f = Foo {
+        headers: vec![],
+        nd: NoDefault(vec![]),
+    }
+    .map_headers(|mut h| {
+        h.push("a".into());
+        h
+    })
+    .with_headers(vec!["b"])
+    .map_headers(|mut h| {
+        h.push("c".into());
+        h
+    })
+    .map_nd(|mut n| {
+        n.0.push("x".into());
+        n
+    });
+    assert_eq!(f.headers, vec!["b", "c"]);
+    assert_eq!(f.nd.0, vec!["x"]);
+}
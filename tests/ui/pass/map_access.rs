@@ -0,0 +1,28 @@
+use std::collections::{BTreeMap, HashMap};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(mut(disable))]
+pub struct Scores {
+    #[property(keys(public), values(public), values_mut(public))]
+    scores: BTreeMap<String, u32>,
+    #[property(values_mut(name = "mut_hashed"))]
+    hashed: HashMap<u8, u8>,
+}
+
+fn main() {
+    let mut value = Scores::default();
+    value.scores.insert("a".to_owned(), 1);
+    value.scores.insert("b".to_owned(), 2);
+    value.hashed.insert(1, 1);
+    let keys: Vec<&String> = value.scores_keys().collect();
+    assert_eq!(keys, vec!["a", "b"]);
+    value.scores_values_mut().for_each(|score| *score *= 10);
+    assert_eq!(
+        value.scores_values().copied().collect::<Vec<_>>(),
+        vec![10, 20]
+    );
+    value.mut_hashed().for_each(|x| *x += 1);
+    assert_eq!(value.hashed[&1], 2);
+}
@@ -0,0 +1,40 @@
+use property::Property;
+use std::collections::{BTreeMap, HashMap};
+#[derive(Debug, PartialEq)]
+enum Value {
+    Int(i64),
+    Text(String),
+}
+impl From<i32> for Value {
+    fn from(v: i32) -> Self {
+        Value::Int(v.into())
+    }
+}
+impl From<&str> for Value {
+    fn from(v: &str) -> Self {
+        Value::Text(v.to_owned())
+    }
+}
+#[derive(Property)]
+struct Foo {
+    values: HashMap<String, Value>,
+    #[property(set(type = "replace"))]
+    names: BTreeMap<u32, String>,
+}
+fn main() {
+    let mut f = Foo {
+        values: HashMap::new(),
+        names: BTreeMap::new(),
+    };
+    f.set_values(vec![("a", 1), ("b", 2)])
+        .set_values([("c", "x")].iter().cloned());
+    assert_eq!(f.values.len(), 1);
+    assert_eq!(f.values["c"], Value::Text("x".to_owned()));
+    let mut m = HashMap::new();
+    m.insert("d".to_owned(), Value::Int(3));
+    f.set_values(m);
+    assert_eq!(f.values["d"], Value::Int(3));
+    assert!(f.set_names(vec![(1u8, "a")]).is_empty());
+    let old = f.set_names(vec![(2u32, "b".to_owned())]);
+    assert_eq!(old[&1], "a");
+}
@@ -0,0 +1,46 @@
+use property::Property;
+#[derive(Property)]
+#[property(merge(public), get(disable), set(disable))]
+struct Foo {
+    #[property(skip)]
+    id: u64,
+    #[property(merge(skip))]
+    created: u64,
+    name: String,
+    tags: Vec<String>,
+}
+#[derive(Property)]
+#[property(merge(name = "update_from"), get(disable), set(disable))]
+struct Bar {
+    level: u8,
+}
+#[derive(Property)]
+#[property(merge(name = "r#move"), get(disable), set(disable))]
+struct Baz {
+    level: u8,
+}
+fn main() {
+    let mut a = Foo {
+        id: 1,
+        created: 10,
+        name: "a".into(),
+        tags: vec![],
+    };
+    let b = Foo {
+        id: 2,
+        created: 20,
+        name: "b".into(),
+        tags: vec!["x".into()],
+    };
+    a.merge_from(b);
+    assert_eq!(
+        (a.id, a.created, a.name.as_str(), a.tags.len()),
+        (1, 10, "b", 1)
+    );
+    let mut c = Bar { level: 1 };
+    c.update_from(Bar { level: 2 });
+    assert_eq!(c.level, 2);
+    let mut z = Baz { level: 1 };
+    z.r#move(Baz { level: 2 });
+    assert_eq!(z.level, 2);
+}
@@ -0,0 +1,17 @@
+#![deny(unused_must_use)]
+use property::Property;
+#[derive(Property)]
+#[property(set(type = "own"), with(public))]
+struct Foo {
+    name: String,
+    #[property(set(allow_unused))]
+    level: u8,
+}
+fn main() {
+    let f = Foo {
+        name: "a".into(),
+        level: 1,
+    };
+    let f = f.set_name("b").with_name("c");
+    f.set_level(2);
+}
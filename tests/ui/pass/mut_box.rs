@@ -0,0 +1,44 @@
+use property::Property;
+
+pub trait Named {
+    fn rename(&mut self, name: &str);
+    fn name(&self) -> &str;
+}
+
+#[derive(Default)]
+pub struct Person(String);
+
+impl Named for Person {
+    fn rename(&mut self, name: &str) {
+        self.0 = name.to_owned();
+    }
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(public))]
+pub struct Holder {
+    number: Box<u32>,
+    named: Box<dyn Named>,
+    #[property(mut(raw))]
+    boxed: Box<u32>,
+}
+
+fn main() {
+    let mut value = Holder {
+        number: Box::new(1),
+        named: Box::new(Person::default()),
+        boxed: Box::new(2),
+    };
+    let number: &mut u32 = value.mut_number();
+    *number += 1;
+    let named: &mut dyn Named = value.mut_named();
+    named.rename("x");
+    let boxed: &mut Box<u32> = value.mut_boxed();
+    *boxed = Box::from(5);
+    assert_eq!(*value.number, 2);
+    assert_eq!(value.named.name(), "x");
+    assert_eq!(*value.boxed, 5);
+}
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use property::Property;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Data(u32);
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(public))]
+pub struct Doc<'a> {
+    text: Cow<'a, str>,
+    items: Cow<'a, [u32]>,
+    data: Cow<'a, Data>,
+    #[property(mut(raw))]
+    raw: Cow<'a, str>,
+}
+
+fn main() {
+    let items = [1, 2];
+    let data = Data(1);
+    let mut value = Doc {
+        text: Cow::Borrowed("ab"),
+        items: Cow::Borrowed(&items[..]),
+        data: Cow::Borrowed(&data),
+        raw: Cow::Borrowed("x"),
+    };
+    let text: &mut String = value.mut_text();
+    text.push('c');
+    let items: &mut Vec<u32> = value.mut_items();
+    items.push(3);
+    value.mut_data().0 = 2;
+    *value.mut_raw() = Cow::Owned("y".to_owned());
+    assert!(matches!(value.text, Cow::Owned(ref s) if s == "abc"));
+    assert!(matches!(value.items, Cow::Owned(ref v) if v == &[1, 2, 3]));
+    assert_eq!(*value.data, Data(2));
+    assert_eq!(data, Data(1));
+    assert!(matches!(value.raw, Cow::Owned(_)));
+}
@@ -0,0 +1,18 @@
+use property::Property;
+#[derive(Property)]
+#[property(get(disable), set(disable))]
+struct Foo {
+    nick: Option<String>,
+    #[property(mut(raw))]
+    note: Option<String>,
+}
+fn main() {
+    let mut f = Foo {
+        nick: Some("a".into()),
+        note: Some("n".into()),
+    };
+    f.mut_nick().unwrap().push('b');
+    assert_eq!(f.nick.as_deref(), Some("ab"));
+    *f.mut_note() = None;
+    assert_eq!(f.note, None);
+}
@@ -0,0 +1,25 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), set(disable), mut(public, raw))]
+pub struct Lists {
+    #[property(mut(type = "inner", or_default))]
+    items: Option<Vec<u32>>,
+    #[property(mut(name = "names", or_default))]
+    others: Option<Vec<String>>,
+}
+
+fn main() {
+    let mut value = Lists::default();
+    assert!(value.mut_items().is_none());
+    value.mut_items_or_default().push(1);
+    value.mut_items_or_default().push(2);
+    if let Some(items) = value.mut_items() {
+        items.push(3);
+    }
+    assert_eq!(value.items, Some(vec![1, 2, 3]));
+    let raw: &mut Option<Vec<String>> = value.names();
+    *raw = None;
+    value.names_or_default().push("a".to_owned());
+    assert_eq!(value.others, Some(vec!["a".to_owned()]));
+}
@@ -0,0 +1,30 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(mut(public))]
+pub struct Foo {
+    #[property(mut(type = "slice"))]
+    items: Vec<u32>,
+    #[property(mut(type = "slice"))]
+    name: String,
+    #[property(mut(type = "slice"))]
+    arr: [u8; 3],
+    others: Vec<u32>,
+}
+
+fn main() {
+    let mut value = Foo {
+        items: vec![1, 2],
+        name: "ab".to_owned(),
+        ..Default::default()
+    };
+    let s: &mut [u32] = value.mut_items();
+    s[0] = 5;
+    value.mut_name().make_ascii_uppercase();
+    value.mut_arr()[1] = 9;
+    value.mut_others().push(3);
+    assert_eq!(value.items, vec![5, 2]);
+    assert_eq!(value.name, "AB");
+    assert_eq!(value.arr, [0, 9, 0]);
+    assert_eq!(value.others, vec![3]);
+}
@@ -0,0 +1,45 @@
+use property::Property;
+#[derive(Property)]
+#[property(new(public), get(disable), set(disable))]
+struct Foo {
+    name: String,
+    tags: Vec<String>,
+    nick: Option<String>,
+    #[property(skip)]
+    cache: Vec<u8>,
+    #[property(new(default))]
+    level: u8,
+}
+#[derive(Property)]
+#[property(new(const, name = "from_parts"), get(disable), set(disable))]
+struct Point {
+    x: i32,
+    y: i32,
+}
+#[derive(Property)]
+#[property(new, get(disable), set(disable))]
+struct Wrapper<T> {
+    inner: T,
+}
+const ORIGIN: Point = Point::from_parts(0, 0);
+#[derive(Property)]
+#[property(new(name = "r#box"), get(disable), set(disable))]
+struct Baz {
+    level: u8,
+}
+fn main() {
+    let f = Foo::new("a", vec!["x", "y"], "n");
+    assert_eq!(
+        (
+            f.name.as_str(),
+            f.tags.len(),
+            f.nick.as_deref(),
+            f.cache.len(),
+            f.level
+        ),
+        ("a", 2, Some("n"), 0, 0)
+    );
+    assert_eq!((ORIGIN.x, ORIGIN.y), (0, 0));
+    assert_eq!(Wrapper::<u64>::new(1u8).inner, 1);
+    assert_eq!(Baz::r#box(2u8).level, 2);
+}
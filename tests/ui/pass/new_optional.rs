@@ -0,0 +1,26 @@
+use property::Property;
+#[derive(Property)]
+#[property(new(optional_defaults), get(disable), set(disable))]
+struct Foo {
+    host: String,
+    nick: Option<String>,
+    port: u16,
+    #[property(default)]
+    tags: Vec<String>,
+    timeout: Option<u64>,
+}
+#[derive(Property)]
+#[property(new(const, optional_defaults), get(disable), set(disable))]
+struct Point {
+    x: i32,
+    label: Option<&'static str>,
+}
+const P: Point = Point::new(1);
+fn main() {
+    let f = Foo::new("h", 80u16);
+    assert_eq!(
+        (f.host.as_str(), f.nick, f.port, f.tags.len(), f.timeout),
+        ("h", None, 80, 0, None)
+    );
+    assert_eq!((P.x, P.label), (1, None));
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(no_coerce))]
+struct Foo {
+    a: Vec<u8>,
+    b: String,
+    c: Option<Vec<u8>>,
+    d: u32,
+}
+#[derive(Property)]
+struct Bar {
+    #[property(get(no_coerce))]
+    a: Vec<u8>,
+    b: String,
+}
+fn main() {
+    let f = Foo {
+        a: Vec::with_capacity(8),
+        b: "x".into(),
+        c: None,
+        d: 1,
+    };
+    let a: &Vec<u8> = f.a();
+    assert!(a.capacity() >= 8);
+    let _: &String = f.b();
+    let _: Option<&Vec<u8>> = f.c();
+    let _: u32 = f.d();
+    let g = Bar {
+        a: vec![],
+        b: "y".into(),
+    };
+    let _: &Vec<u8> = g.a();
+    let _: &str = g.b();
+}
@@ -0,0 +1,58 @@
+#![no_std]
+
+extern crate alloc;
+// The runtime is still required by the test binary, but `::std` is not in scope.
+extern crate std as _;
+
+use alloc::vec::Vec;
+
+use property::Property;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    level: u8,
+}
+
+#[derive(Property, Default)]
+#[property(set(strip_option))]
+pub struct Service {
+    config: Option<alloc::boxed::Box<Config>>,
+    #[property(set(type = "replace"))]
+    backup: Option<alloc::boxed::Box<Config>>,
+    #[property(truncate, resize, drain(range))]
+    levels: Vec<u8>,
+}
+
+#[derive(Property, Default)]
+pub struct Registry {
+    #[property(into_iterator)]
+    entries: alloc::collections::BTreeMap<u8, Config>,
+}
+
+fn main() {
+    let mut service = Service::default();
+    service.set_config(Config { level: 1 });
+    assert_eq!(
+        service.config(),
+        Some(&alloc::boxed::Box::new(Config { level: 1 }))
+    );
+    assert_eq!(service.set_backup(Config { level: 2 }), None);
+    assert_eq!(
+        service.set_backup(Config { level: 3 }),
+        Some(alloc::boxed::Box::new(Config { level: 2 }))
+    );
+    service.resize_levels(3, 1).truncate_levels(2);
+    assert_eq!(service.levels(), &[1, 1]);
+    assert_eq!(service.drain_levels_range(1..), [1]);
+    assert_eq!(service.drain_levels(), [1]);
+
+    let mut registry = Registry::default();
+    registry.entries.insert(1, Config { level: 1 });
+    for (_, config) in &mut registry {
+        config.level += 1;
+    }
+    let levels: Vec<u8> = (&registry).into_iter().map(|(_, c)| c.level).collect();
+    assert_eq!(levels, [2]);
+    let keys: Vec<u8> = registry.into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, [1]);
+}
@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(set(on_change = "Self::bump"), get(disable), with(public))]
+struct Foo {
+    #[property(skip)]
+    version: u32,
+    #[property(set(clone_from, if_some))]
+    name: String,
+    #[property(set(strip_option))]
+    nick: Option<String>,
+    #[property(set(track_change))]
+    level: u8,
+    #[property(set(type = "replace", on_change = "Self::log"))]
+    score: u32,
+    #[property(set(min = "1", max = "9", type = "own"))]
+    rank: u8,
+}
+impl Foo {
+    fn bump(&mut self) {
+        self.version += 1;
+    }
+    fn log(&mut self, old: &u32) {
+        self.version += *old;
+    }
+}
+fn main() {
+    let mut f = Foo {
+        version: 0,
+        name: "a".into(),
+        nick: None,
+        level: 1,
+        score: 10,
+        rank: 1,
+    };
+    f.set_name("b")
+        .set_name_from(&"c".to_owned())
+        .set_name_if_some(Some("d"));
+    assert_eq!(f.version, 3);
+    f.set_nick("x").set_nick_opt(None);
+    assert_eq!(f.version, 5);
+    assert!(!f.set_level(1));
+    assert!(f.set_level(2));
+    assert_eq!(f.version, 6);
+    assert_eq!(f.set_score(20u32), 10);
+    assert_eq!(f.version, 16);
+    let f = f.set_rank(3).unwrap();
+    assert_eq!(f.version, 17);
+    let f = f.with_level(5);
+    assert_eq!(f.version, 18);
+    let f = f.with_score(1u32);
+    assert_eq!(f.version, 38);
+}
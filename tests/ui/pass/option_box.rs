@@ -0,0 +1,65 @@
+use property::Property;
+#[derive(Debug, PartialEq)]
+struct Config {
+    level: u8,
+}
+impl From<u8> for Config {
+    fn from(level: u8) -> Self {
+        Config { level }
+    }
+}
+trait Named {
+    fn name(&self) -> &str;
+}
+impl Named for Config {
+    fn name(&self) -> &str {
+        "config"
+    }
+}
+#[derive(Property)]
+#[property(set(strip_option))]
+struct Foo {
+    config: Option<Box<Config>>,
+    #[property(set(type = "replace"))]
+    backup: Option<Box<Config>>,
+    #[property(set(full_option))]
+    plain: Option<Box<Config>>,
+    #[property(set(validator = "check", error = "String"))]
+    checked: Option<Box<Config>>,
+    named: Option<Box<dyn Named>>,
+    bytes: Option<Box<[u8]>>,
+    text: Option<Box<str>>,
+}
+fn check(c: &Config) -> Result<(), String> {
+    if c.level > 9 {
+        Err("too high".to_owned())
+    } else {
+        Ok(())
+    }
+}
+fn main() {
+    let mut f = Foo {
+        config: None,
+        backup: None,
+        plain: None,
+        checked: None,
+        named: None,
+        bytes: None,
+        text: None,
+    };
+    f.set_config(Config { level: 1 })
+        .set_config_opt(None)
+        .set_config(2u8);
+    assert_eq!(f.config, Some(Box::new(Config { level: 2 })));
+    assert_eq!(f.set_backup(Config { level: 3 }), None);
+    assert_eq!(f.set_backup(4u8), Some(Box::new(Config { level: 3 })));
+    f.set_plain(Some(Box::new(Config { level: 5 })));
+    assert!(f.set_checked(Config { level: 6 }).is_ok());
+    assert!(f.set_checked(10u8).is_err());
+    assert_eq!(f.checked, Some(Box::new(Config { level: 6 })));
+    let named: Box<dyn Named> = Box::new(Config { level: 7 });
+    f.set_named(named).set_bytes(vec![1u8, 2]).set_text("abc");
+    assert_eq!(f.named.as_ref().map(|n| n.name()), Some("config"));
+    assert_eq!(f.bytes.as_deref(), Some(&[1u8, 2][..]));
+    assert_eq!(f.text.as_deref(), Some("abc"));
+}
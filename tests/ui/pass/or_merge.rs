@@ -0,0 +1,61 @@
+use property::Property;
+#[derive(Default, Property)]
+#[property(merge(options), get(disable), set(disable))]
+struct Config {
+    host: Option<String>,
+    port: Option<u16>,
+    verbose: Option<bool>,
+    #[property(merge(skip))]
+    source: Option<String>,
+    level: u8,
+}
+#[derive(Property)]
+#[property(merge(options, overwrite, name = "layer"), get(disable), set(disable))]
+struct Other {
+    port: Option<u16>,
+    level: u8,
+}
+#[derive(Property)]
+#[property(merge(options), get(disable), set(disable))]
+struct Empty {
+    level: u8,
+}
+fn main() {
+    let defaults = Config {
+        host: Some("localhost".into()),
+        port: Some(80),
+        verbose: Some(false),
+        source: Some("d".into()),
+        level: 1,
+    };
+    let file = Config {
+        host: Some("example.com".into()),
+        port: None,
+        verbose: None,
+        source: Some("f".into()),
+        level: 2,
+    };
+    let mut cli = Config {
+        port: Some(8080),
+        level: 3,
+        ..Default::default()
+    };
+    cli.or_merge(file).or_merge(defaults);
+    assert_eq!(cli.host.as_deref(), Some("example.com"));
+    assert_eq!(cli.port, Some(8080));
+    assert_eq!(cli.verbose, Some(false));
+    assert_eq!(cli.source, None);
+    assert_eq!(cli.level, 3);
+    let mut o = Other {
+        port: None,
+        level: 1,
+    };
+    o.layer(Other {
+        port: Some(1),
+        level: 2,
+    });
+    assert_eq!((o.port, o.level), (Some(1), 2));
+    let mut e = Empty { level: 1 };
+    e.or_merge(Empty { level: 2 });
+    assert_eq!(e.level, 1);
+}
@@ -0,0 +1,51 @@
+use std::cmp::Ordering;
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(all))]
+pub struct Version {
+    major: u8,
+    #[property(ord(desc))]
+    minor: u8,
+    #[property(ord(skip))]
+    note: String,
+    patch: u8,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(all))]
+pub struct Reordered {
+    patch: u8,
+    major: u8,
+}
+
+fn version(major: u8, minor: u8, patch: u8, note: &str) -> Version {
+    Version {
+        major,
+        minor,
+        note: note.to_owned(),
+        patch,
+    }
+}
+
+fn main() {
+    assert_eq!(
+        version(1, 0, 9, "").partial_cmp(&version(2, 0, 0, "")),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        version(1, 1, 0, "").partial_cmp(&version(1, 2, 0, "")),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        version(1, 1, 0, "").partial_cmp(&version(1, 1, 1, "")),
+        Some(Ordering::Less)
+    );
+    assert!(version(1, 1, 1, "a") == version(1, 1, 1, "b"));
+    let _ = &version(0, 0, 0, "").note;
+    assert_eq!(
+        Reordered { patch: 9, major: 1 }.partial_cmp(&Reordered { patch: 0, major: 2 }),
+        Some(Ordering::Greater)
+    );
+}
@@ -0,0 +1,63 @@
+use std::cmp::Ordering;
+
+use property::Property;
+
+pub struct Url {
+    scheme: String,
+    host: String,
+}
+
+pub fn host_of(url: &Url) -> &str {
+    &url.host
+}
+
+fn score_of(score: &f64) -> i64 {
+    *score as i64
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Site {
+    #[property(ord(_2))]
+    id: u32,
+    #[property(ord(_1, by = "crate::host_of"))]
+    url: Url,
+    #[property(ord(_3, desc, by = "score_of"))]
+    score: f64,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full), hash)]
+pub struct Full {
+    #[property(ord(_1, by = "score_of"))]
+    score: f64,
+}
+
+fn site(id: u32, host: &str, score: f64) -> Site {
+    Site {
+        id,
+        url: Url {
+            scheme: "https".to_owned(),
+            host: host.to_owned(),
+        },
+        score,
+    }
+}
+
+fn main() {
+    let a = site(2, "a.com", 1.0);
+    let b = site(1, "b.com", 1.0);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+    let c = site(1, "a.com", 1.0);
+    assert_eq!(a.partial_cmp(&c), Some(Ordering::Greater));
+    let d = site(2, "a.com", 3.0);
+    assert_eq!(a.partial_cmp(&d), Some(Ordering::Greater));
+    let mut e = site(2, "a.com", 1.5);
+    e.url.scheme = "http".to_owned();
+    assert!(a == e);
+    let _ = &a.url.scheme;
+    assert_eq!(
+        Full { score: 1.2 }.cmp(&Full { score: 1.9 }),
+        Ordering::Equal
+    );
+}
@@ -0,0 +1,43 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), sort_key)]
+pub struct Post {
+    #[property(ord(_1, by_len))]
+    tags: Vec<String>,
+    #[property(ord(_2, desc, by_len))]
+    meta: HashMap<String, u8>,
+    #[property(ord(_3))]
+    id: u32,
+}
+
+fn post(tags: &[&str], meta: usize, id: u32) -> Post {
+    Post {
+        tags: tags.iter().map(|t| (*t).to_owned()).collect(),
+        meta: (0..meta).map(|i| (i.to_string(), 0)).collect(),
+        id,
+    }
+}
+
+fn main() {
+    assert_eq!(
+        post(&["a"], 0, 1).partial_cmp(&post(&["b"], 0, 2)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        post(&["a"], 0, 2).partial_cmp(&post(&["b"], 0, 1)),
+        Some(Ordering::Greater)
+    );
+    assert!(post(&["a"], 1, 1) == post(&["b"], 1, 1));
+    assert_eq!(
+        post(&["z"], 0, 1).partial_cmp(&post(&["a", "b"], 0, 1)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        post(&[], 2, 9).partial_cmp(&post(&[], 1, 1)),
+        Some(Ordering::Less)
+    );
+    assert!(post(&[], 2, 9).sort_key() < post(&[], 1, 1).sort_key());
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use property::Property;
+
+#[derive(Property, Debug, Clone)]
+#[property(get(disable), set(disable), mut(disable), ord(full))]
+pub struct Version {
+    #[property(ord(_0))]
+    major: u32,
+    #[property(ord(desc, _1))]
+    minor: u32,
+    #[property(ord(_2))]
+    tag: String,
+    note: f64,
+}
+
+fn v(major: u32, minor: u32, tag: &str) -> Version {
+    Version {
+        major,
+        minor,
+        tag: tag.to_owned(),
+        note: 0.0,
+    }
+}
+
+fn main() {
+    let mut list = [v(2, 0, "a"), v(1, 0, "b"), v(1, 3, "a"), v(1, 3, "0")];
+    list.sort();
+    let keys: Vec<_> = list
+        .iter()
+        .map(|x| (x.major, x.minor, x.tag.clone()))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (1, 3, "0".to_owned()),
+            (1, 3, "a".to_owned()),
+            (1, 0, "b".to_owned()),
+            (2, 0, "a".to_owned())
+        ]
+    );
+    let mut map = BTreeMap::new();
+    map.insert(v(1, 0, "a"), 1);
+    let mut other = v(1, 0, "a");
+    other.note = 1.0;
+    assert_eq!(map.get(&other), Some(&1));
+    assert!(v(1, 0, "a") > v(0, 9, "z"));
+    assert_eq!(
+        v(1, 0, "a").partial_cmp(&v(1, 0, "a")),
+        Some(std::cmp::Ordering::Equal)
+    );
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use property::Property;
+
+#[derive(Property, Debug)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Pair<K, V> {
+    #[property(ord(_0))]
+    key: K,
+    value: V,
+}
+
+#[derive(Property, Debug)]
+#[property(get(disable), set(disable), mut(disable), ord(full))]
+pub struct Entry<'a, K>
+where
+    K: Clone,
+{
+    #[property(ord(desc, _0))]
+    key: K,
+    #[property(ord(_1))]
+    name: &'a str,
+}
+
+fn main() {
+    let a = Pair {
+        key: 1u8,
+        value: 0.5,
+    };
+    let b = Pair {
+        key: 1u8,
+        value: 1.5,
+    };
+    let c = Pair {
+        key: 2u8,
+        value: 0.5,
+    };
+    assert_eq!(a, b);
+    assert!(a < c);
+
+    let mut list = vec![
+        Entry {
+            key: 1u32,
+            name: "b",
+        },
+        Entry {
+            key: 2u32,
+            name: "c",
+        },
+        Entry {
+            key: 1u32,
+            name: "a",
+        },
+    ];
+    list.sort();
+    let names: Vec<_> = list.iter().map(|e| e.name).collect();
+    assert_eq!(names, ["c", "a", "b"]);
+    let map: BTreeMap<_, _> = list.into_iter().map(|e| (e, ())).collect();
+    assert_eq!(map.keys().next().map(|e| e.key), Some(2));
+}
@@ -0,0 +1,40 @@
+use std::cmp::Ordering;
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(desc))]
+pub struct Score {
+    #[property(ord(_1))]
+    points: u32,
+    #[property(ord(_2, asc))]
+    name: String,
+    #[property(ord(_3))]
+    level: u8,
+}
+
+fn score(points: u32, name: &str, level: u8) -> Score {
+    Score {
+        points,
+        name: name.to_owned(),
+        level,
+    }
+}
+
+fn main() {
+    // Inherited: descending.
+    assert_eq!(
+        score(2, "a", 0).partial_cmp(&score(1, "a", 0)),
+        Some(Ordering::Less)
+    );
+    // Overridden: ascending.
+    assert_eq!(
+        score(1, "a", 0).partial_cmp(&score(1, "b", 0)),
+        Some(Ordering::Less)
+    );
+    // Inherited again after an overridden field.
+    assert_eq!(
+        score(1, "a", 1).partial_cmp(&score(1, "a", 2)),
+        Some(Ordering::Greater)
+    );
+}
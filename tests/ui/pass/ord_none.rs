@@ -0,0 +1,54 @@
+use std::cmp::Ordering;
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Task {
+    #[property(ord(_1, none_last))]
+    priority: Option<u8>,
+    #[property(ord(_2, desc, none_first))]
+    level: Option<u8>,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full))]
+pub struct Job {
+    #[property(ord(_1, desc, none_last))]
+    priority: Option<u8>,
+}
+
+fn task(priority: Option<u8>, level: Option<u8>) -> Task {
+    Task { priority, level }
+}
+
+fn main() {
+    assert_eq!(
+        task(None, None).partial_cmp(&task(Some(9), None)),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        task(Some(1), None).partial_cmp(&task(Some(2), None)),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        task(None, None).partial_cmp(&task(None, None)),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        task(None, None).partial_cmp(&task(None, Some(1))),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        task(None, Some(1)).partial_cmp(&task(None, Some(2))),
+        Some(Ordering::Greater)
+    );
+    let mut jobs = [
+        Job { priority: None },
+        Job { priority: Some(1) },
+        Job { priority: Some(3) },
+    ];
+    jobs.sort();
+    let priorities: Vec<_> = jobs.iter().map(|j| j.priority).collect();
+    assert_eq!(priorities, vec![Some(3), Some(1), None]);
+}
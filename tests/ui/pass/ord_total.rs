@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+
+use property::Property;
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable))]
+pub struct Point {
+    #[property(ord(_1, total))]
+    x: f64,
+    #[property(ord(_2, desc))]
+    id: u8,
+}
+
+#[derive(Property)]
+#[property(get(disable), set(disable), mut(disable), ord(full, total))]
+pub struct Sample {
+    #[property(ord(_1))]
+    value: f32,
+    #[property(ord(_2, desc))]
+    id: u8,
+}
+
+fn main() {
+    let nan = Point { x: f64::NAN, id: 1 };
+    assert_eq!(
+        nan.partial_cmp(&Point { x: f64::NAN, id: 1 }),
+        Some(Ordering::Equal)
+    );
+    assert!(nan == Point { x: f64::NAN, id: 1 });
+    assert_eq!(
+        nan.partial_cmp(&Point { x: 1.0, id: 1 }),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        Point { x: 1.0, id: 1 }.partial_cmp(&Point { x: 1.0, id: 2 }),
+        Some(Ordering::Greater)
+    );
+    let mut samples = [
+        Sample {
+            value: f32::NAN,
+            id: 1,
+        },
+        Sample { value: 2.0, id: 2 },
+        Sample { value: -1.0, id: 3 },
+        Sample { value: 2.0, id: 4 },
+    ];
+    samples.sort();
+    let ids: Vec<_> = samples.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![3, 4, 2, 1]);
+}
@@ -0,0 +1,33 @@
+use property::Property;
+
+#[derive(Property, Debug, PartialEq, Clone)]
+#[property(get(disable), set(disable), mut(disable), parts(public))]
+pub struct Point {
+    x: i32,
+    #[property(skip)]
+    cache: Option<String>,
+    y: i32,
+    r#type: String,
+}
+
+#[derive(Property)]
+#[property(parts)]
+pub struct Wrapper<Item> {
+    items: Vec<Item>,
+}
+
+fn main() {
+    let p = Point {
+        x: 1,
+        cache: None,
+        y: 2,
+        r#type: "a".to_owned(),
+    };
+    let parts: (i32, i32, String) = p.clone().into_parts();
+    assert_eq!(parts, (1, 2, "a".to_owned()));
+    let (x, y, t) = parts;
+    assert_eq!(Point::from_parts((x, y, t)), p);
+    let w = Wrapper::from_parts((vec![1u8],));
+    assert_eq!(w.into_parts(), (vec![1u8],));
+    let _ = p.cache;
+}
@@ -0,0 +1,57 @@
+#![allow(non_camel_case_types)]
+use property::Property;
+#[derive(Property)]
+#[property(patch(public), get(disable), set(disable))]
+struct Foo {
+    #[property(skip)]
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+    nick: Option<String>,
+    #[property(set(name = "level_to"))]
+    level: u8,
+}
+#[derive(Property)]
+#[property(patch(name = "BarUpdate"), get(disable), set(disable))]
+struct Bar {
+    level: u8,
+}
+#[derive(Property)]
+#[property(patch(name = "r#type"), get(disable), set(disable))]
+struct Baz {
+    level: u8,
+}
+fn main() {
+    let mut f = Foo {
+        id: 1,
+        name: "a".into(),
+        tags: vec![],
+        nick: Some("n".into()),
+        level: 1,
+    };
+    FooPatch::default()
+        .set_name("b")
+        .level_to(3u8)
+        .apply_to(&mut f);
+    assert_eq!(
+        (
+            f.id,
+            f.name.as_str(),
+            f.tags.len(),
+            f.nick.as_deref(),
+            f.level
+        ),
+        (1, "b", 0, Some("n"), 3)
+    );
+    FooPatch::default()
+        .set_tags(vec!["x"])
+        .set_nick("m")
+        .apply_to(&mut f);
+    assert_eq!((f.tags.len(), f.nick.as_deref()), (1, Some("m")));
+    let mut b = Bar { level: 1 };
+    BarUpdate::default().apply_to(&mut b);
+    assert_eq!(b.level, 1);
+    let mut z = Baz { level: 1 };
+    r#type::default().set_level(2u8).apply_to(&mut z);
+    assert_eq!(z.level, 2);
+}
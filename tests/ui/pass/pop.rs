@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::VecDeque;
+#[derive(Property, Default)]
+#[property(push, pop)]
+struct Foo {
+    names: Vec<String>,
+    queue: VecDeque<u32>,
+    other: u8,
+    #[property(skip)]
+    skipped: Vec<u8>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.push_names("a").push_names("b");
+    assert_eq!(f.pop_names().as_deref(), Some("b"));
+    assert_eq!(f.pop_names().as_deref(), Some("a"));
+    assert_eq!(f.pop_names(), None);
+    f.push_queue(1u32).push_queue(2u32);
+    assert_eq!(f.pop_queue(), Some(2));
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+use property::Property;
+use std::collections::VecDeque;
+#[derive(Property, Default)]
+#[property(push(public))]
+struct Foo {
+    names: Vec<String>,
+    queue: VecDeque<u32>,
+    other: u8,
+    #[property(push(name = "add_item"))]
+    items: Vec<u8>,
+}
+#[derive(Property, Default)]
+struct Bar {
+    #[property(push)]
+    names: Vec<String>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.push_names("a").push_names(String::from("b"));
+    assert_eq!(f.names, vec!["a", "b"]);
+    f.push_queue(1u16).push_queue(2u32);
+    assert_eq!(f.queue, vec![1, 2]);
+    f.add_item(1u8);
+    let mut b = Bar::default();
+    b.push_names("x");
+}
@@ -0,0 +1,22 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), push_str(public), push_char(public))]
+pub struct Text {
+    text: String,
+    #[property(push_str(name = "write"))]
+    other: String,
+    count: u32,
+}
+
+fn main() {
+    let mut value = Text::default();
+    value
+        .push_str_text("ab")
+        .push_str_text(String::from("cd"))
+        .push_char_text('!')
+        .write("x");
+    assert_eq!(value.text, "abcd!");
+    assert_eq!(value.other, "x");
+    assert_eq!(value.count, 0);
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+struct Foo {
+    #[property(set(min = "0", max = "100", clamp))]
+    percent: u8,
+    #[property(set(min = "-1", max = "1"))]
+    ratio: f64,
+    #[property(set(min = "10", type = "replace"))]
+    opt: Option<i32>,
+    #[property(set(max = "0.5", clamp, type = "none"))]
+    half: f32,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_percent(200u8).set_percent(100u8);
+    assert_eq!(f.percent, 100);
+    f.set_percent(250u8);
+    assert_eq!(f.percent, 100);
+    assert!(f.set_ratio(1.0).is_ok());
+    assert!(f.set_ratio(-1.0).is_ok());
+    assert_eq!(
+        f.set_ratio(1.5).err(),
+        Some("the value is greater than the maximum")
+    );
+    assert_eq!(f.ratio, -1.0);
+    assert_eq!(f.set_opt(10), Ok(None));
+    assert!(f.set_opt(9).is_err());
+    f.set_half(3.0f32);
+    assert_eq!(f.half, 0.5);
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(mut(suffix = "_mut"))]
+struct Foo {
+    r#type: String,
+    r#fn: u8,
+    r#loop: Vec<u8>,
+}
+fn main() {
+    let mut f = Foo {
+        r#type: "x".into(),
+        r#fn: 1,
+        r#loop: vec![],
+    };
+    assert_eq!(f.r#type(), "x");
+    f.set_type("y").set_fn(3u8).set_loop(vec![1u8]);
+    f.type_mut().push('z');
+    *f.fn_mut() += 1;
+    f.loop_mut().push(2);
+    assert_eq!(f.r#type(), "yz");
+    assert_eq!(f.r#fn(), 4);
+    assert_eq!(f.r#loop(), &[1, 2]);
+}
@@ -0,0 +1,24 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(type = "auto"))]
+struct Foo {
+    a: Option<String>,
+    #[property(get(raw_option))]
+    b: Option<String>,
+    #[property(get(type = "clone", raw_option))]
+    c: Option<u8>,
+}
+fn main() {
+    let f = Foo {
+        a: Some("x".into()),
+        b: None,
+        c: Some(1),
+    };
+    let a: Option<&String> = f.a();
+    let b: &Option<String> = f.b();
+    let c: &Option<u8> = f.c();
+    assert_eq!(a.map(String::as_str), Some("x"));
+    assert!(b.is_none());
+    assert_eq!(c, &Some(1));
+}
@@ -0,0 +1,30 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), reserve(public))]
+pub struct Buffers {
+    items: Vec<u32>,
+    text: String,
+    queue: VecDeque<u8>,
+    map: HashMap<u8, u8>,
+    set: HashSet<u8>,
+    sorted: BTreeMap<u8, u8>,
+}
+
+fn main() {
+    let mut value = Buffers::default();
+    value
+        .reserve_items(100)
+        .reserve_text(10)
+        .reserve_queue(10)
+        .reserve_map(10)
+        .reserve_set(10);
+    assert!(value.items.capacity() >= 100);
+    assert!(value.text.capacity() >= 10);
+    assert!(value.queue.capacity() >= 10);
+    assert!(value.map.capacity() >= 10);
+    assert!(value.set.capacity() >= 10);
+    assert!(value.sorted.is_empty());
+}
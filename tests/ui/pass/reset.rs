@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use property::Property;
+struct Id(u32);
+#[derive(Property)]
+#[property(reset)]
+struct Foo {
+    #[property(reset(disable))]
+    id: Id,
+    name: String,
+    list: Vec<u8>,
+}
+fn main() {
+    let mut f = Foo {
+        id: Id(1),
+        name: "a".into(),
+        list: vec![1],
+    };
+    f.reset_name().reset_list();
+    assert!(f.name.is_empty() && f.list.is_empty());
+    assert_eq!(f.id.0, 1);
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+use property::Property;
+struct Id(u32);
+#[derive(Property)]
+#[property(reset_all(name = "clear", public))]
+struct Foo {
+    #[property(skip)]
+    id: Id,
+    #[property(reset(skip))]
+    tag: u8,
+    name: String,
+    list: Vec<u8>,
+}
+#[derive(Property)]
+#[property(reset_all(name = "r#match"))]
+struct Bar {
+    name: String,
+}
+fn main() {
+    let mut f = Foo {
+        id: Id(1),
+        tag: 3,
+        name: "a".into(),
+        list: vec![1],
+    };
+    f.clear();
+    assert!(f.name.is_empty() && f.list.is_empty());
+    assert_eq!((f.id.0, f.tag), (1, 3));
+    let mut b = Bar { name: "b".into() };
+    b.r#match();
+    assert!(b.name.is_empty());
+}
@@ -0,0 +1,36 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), retain(public))]
+pub struct Filters {
+    numbers: Vec<u32>,
+    queue: VecDeque<u32>,
+    map: BTreeMap<String, u32>,
+    set: HashSet<u32>,
+}
+
+fn main() {
+    let mut value = Filters {
+        numbers: vec![1, 2, 3, 4],
+        queue: vec![1, 2].into(),
+        set: vec![1, 2, 3].into_iter().collect(),
+        ..Default::default()
+    };
+    value.map.insert("keep_a".to_owned(), 1);
+    value.map.insert("drop_b".to_owned(), 2);
+    value
+        .retain_numbers(|x| x % 2 == 0)
+        .retain_queue(|x| *x > 1)
+        .retain_set(|x| *x != 2);
+    value.retain_map(|key, v| {
+        *v += 10;
+        key.starts_with("keep_")
+    });
+    assert_eq!(value.numbers, vec![2, 4]);
+    assert_eq!(value.queue, vec![2]);
+    assert_eq!(value.set.len(), 2);
+    assert_eq!(value.map.len(), 1);
+    assert_eq!(value.map["keep_a"], 11);
+}
@@ -0,0 +1,19 @@
+use property::Property;
+#[derive(Property)]
+struct Foo {
+    #[property(set(fmt))]
+    title: String,
+}
+fn main() {
+    let mut f = Foo {
+        title: String::new(),
+    };
+    f.set_title_fmt(format_args!("run {}", 1000));
+    assert_eq!(f.title, "run 1000");
+    let cap = f.title.capacity();
+    for n in 0..100 {
+        f.set_title_fmt(format_args!("run {}", n));
+    }
+    assert_eq!(f.title, "run 99");
+    assert_eq!(f.title.capacity(), cap);
+}
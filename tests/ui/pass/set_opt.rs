@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(strip_option))]
+struct Foo {
+    name: Option<String>,
+    #[property(set(type = "replace"))]
+    b: Option<u8>,
+    #[property(set(type = "own"))]
+    list: Option<Vec<u8>>,
+    c: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_name_opt(Some(String::from("x")));
+    assert_eq!(f.name.as_deref(), Some("x"));
+    f.set_name_opt(None);
+    assert_eq!(f.name, None);
+    f.set_name_opt(String::from("y"));
+    assert_eq!(f.name.as_deref(), Some("y"));
+    assert_eq!(f.set_b_opt(1), None);
+    assert_eq!(f.set_b_opt(None), Some(1));
+    let f = f.set_list_opt(vec![1u8]);
+    assert_eq!(f.list, Some(vec![1]));
+}
@@ -0,0 +1,37 @@
+use property::Property;
+#[derive(Property)]
+#[property(set(vec))]
+struct Foo {
+    items: Vec<u64>,
+    #[property(set(type = "replace"))]
+    names: Vec<String>,
+    level: u8,
+    #[property(set(validator = "not_empty", error = "&'static str"))]
+    checked: Vec<u8>,
+}
+fn not_empty(v: &[u8]) -> Result<(), &'static str> {
+    if v.is_empty() {
+        Err("empty")
+    } else {
+        Ok(())
+    }
+}
+fn main() {
+    let mut f = Foo {
+        items: vec![],
+        names: vec![],
+        level: 0,
+        checked: vec![1],
+    };
+    let v: Vec<u64> = Vec::with_capacity(1000);
+    let (ptr, cap) = (v.as_ptr(), v.capacity());
+    f.set_items_vec(v).set_level(1);
+    assert_eq!((f.items.as_ptr(), f.items.capacity()), (ptr, cap));
+    let old = f.set_names_vec(vec!["a".to_owned()]);
+    assert!(old.is_empty());
+    assert_eq!(f.set_names_vec(vec![]), vec!["a".to_owned()]);
+    assert!(f.set_checked_vec(vec![]).is_err());
+    assert_eq!(f.checked, vec![1]);
+    assert!(f.set_checked_vec(vec![2]).is_ok());
+    assert_eq!(f.checked, vec![2]);
+}
@@ -0,0 +1,36 @@
+use std::collections::{BTreeSet, HashMap};
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), size(public))]
+pub struct Sizes {
+    text: String,
+    items: Vec<u32>,
+    map: HashMap<String, u32>,
+    set: BTreeSet<u8>,
+    arr: [u8; 2],
+    #[property(size(prefix = "count_"))]
+    named: Vec<u8>,
+    number: u32,
+}
+
+fn main() {
+    let mut value = Sizes {
+        text: "ab".to_owned(),
+        ..Default::default()
+    };
+    value.map.insert("a".to_owned(), 1);
+    assert_eq!(value.text_len(), 2);
+    assert!(!value.text_is_empty());
+    assert_eq!(value.items_len(), 0);
+    assert!(value.items_is_empty());
+    assert_eq!(value.map_len(), 1);
+    assert!(!value.map_is_empty());
+    assert!(value.set_is_empty());
+    assert_eq!(value.arr_len(), 2);
+    assert!(!value.arr_is_empty());
+    assert!(value.count_named_is_empty());
+    assert_eq!(value.count_named_len(), 0);
+    assert_eq!(value.number, 0);
+}
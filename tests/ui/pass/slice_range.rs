@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(slice_range))]
+struct Foo {
+    a: Vec<u8>,
+    b: [u8; 4],
+    c: String,
+}
+fn main() {
+    let f = Foo {
+        a: vec![1, 2, 3],
+        b: [1, 2, 3, 4],
+        c: String::new(),
+    };
+    assert_eq!(f.a_slice(..), Some(&[1, 2, 3][..]));
+    assert_eq!(f.a_slice(1..), Some(&[2, 3][..]));
+    assert_eq!(f.a_slice(..=1), Some(&[1, 2][..]));
+    assert_eq!(f.a_slice(2..5), None);
+    assert_eq!(f.a_slice(..=usize::MAX), None);
+    assert_eq!(f.b_slice(3..), Some(&[4][..]));
+}
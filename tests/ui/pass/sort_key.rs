@@ -0,0 +1,62 @@
+use std::cmp::{Ordering, Reverse};
+
+use property::Property;
+
+#[derive(Property, Clone)]
+#[property(get(disable), set(disable), mut(disable), sort_key(public))]
+pub struct Person {
+    #[property(ord(_2, desc))]
+    age: u8,
+    #[property(ord(_1))]
+    name: String,
+    #[property(ord(_3))]
+    nick: Option<String>,
+    #[property(ord(_4))]
+    scores: Vec<u8>,
+    id: u32,
+}
+
+#[derive(Property)]
+#[property(sort_key(crate, name = "key"))]
+pub struct Pair {
+    #[property(ord(_1))]
+    left: u8,
+}
+
+fn person(name: &str, age: u8, id: u32) -> Person {
+    Person {
+        age,
+        name: name.to_owned(),
+        nick: None,
+        scores: vec![id as u8],
+        id,
+    }
+}
+
+#[derive(Property)]
+#[property(sort_key(crate, name = "r#use"))]
+pub struct Raw {
+    #[property(ord(_1))]
+    left: u8,
+}
+
+fn main() {
+    let mut people = vec![
+        person("bob", 20, 1),
+        person("alice", 20, 2),
+        person("bob", 30, 3),
+        person("alice", 10, 4),
+    ];
+    let key: (&str, Reverse<&u8>, Option<&String>, &[u8]) = people[0].sort_key();
+    assert_eq!(key, ("bob", Reverse(&20), None, &[1][..]));
+    let mut sorted = people.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    people.sort_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+    let ids: Vec<_> = people.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![2, 4, 3, 1]);
+    assert_eq!(ids, sorted.iter().map(|p| p.id).collect::<Vec<_>>());
+    let pair = Pair { left: 1 };
+    assert_eq!(pair.key(), (&1,));
+    assert_eq!(pair.partial_cmp(&Pair { left: 2 }), Some(Ordering::Less));
+    assert_eq!(Raw { left: 3 }.r#use(), (&3,));
+}
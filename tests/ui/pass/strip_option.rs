@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(full_option))]
+struct Foo {
+    #[property(set(strip_option))]
+    name: Option<String>,
+    #[property(set(strip_option, type = "own"))]
+    a: Option<u8>,
+    #[property(set(strip_option, type = "replace"))]
+    b: Option<String>,
+    #[property(set(strip_option, type = "none"))]
+    c: Option<String>,
+    d: Option<u8>,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_name("hello");
+    assert_eq!(f.name().map(String::as_str), Some("hello"));
+    let mut f = f.set_a(1u8);
+    assert_eq!(f.set_b("x"), None);
+    assert_eq!(f.set_b("y"), Some("x".to_owned()));
+    f.set_c("z");
+    f.set_d(None);
+    assert_eq!(f.a(), Some(1));
+}
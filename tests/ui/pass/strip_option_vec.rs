@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(set(strip_option))]
+struct Foo {
+    names: Option<Vec<String>>,
+    #[property(set(type = "replace"))]
+    tags: Option<Vec<String>>,
+    #[property(set(type = "own"))]
+    nums: Option<Vec<u32>>,
+    #[property(set(type = "none"))]
+    other: Option<Vec<u8>>,
+    plain: Option<String>,
+    x: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_names(["a", "b"].iter().copied());
+    assert_eq!(f.names, Some(vec!["a".to_owned(), "b".to_owned()]));
+    assert_eq!(f.set_tags(vec!["x"]), None);
+    assert_eq!(f.set_tags(vec!["y"]), Some(vec!["x".to_owned()]));
+    f.set_other(vec![1u8]);
+    f.set_plain("p");
+    let f = f.set_nums(vec![1u16, 2]);
+    assert_eq!(f.nums, Some(vec![1, 2]));
+}
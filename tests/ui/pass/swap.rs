@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(swap)]
+struct Foo {
+    name: String,
+    n: u8,
+}
+fn main() {
+    let mut a = Foo {
+        name: "a".into(),
+        n: 1,
+    };
+    let mut b = Foo {
+        name: "b".into(),
+        n: 2,
+    };
+    a.swap_name(&mut b);
+    assert_eq!((a.name.as_str(), b.name.as_str()), ("b", "a"));
+    assert_eq!((a.n, b.n), (1, 2));
+}
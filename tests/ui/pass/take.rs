@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(take)]
+struct Foo {
+    a: Option<String>,
+    b: Option<u8>,
+    c: u8,
+}
+fn main() {
+    let mut f = Foo {
+        a: Some("x".into()),
+        b: Some(1),
+        c: 0,
+    };
+    assert_eq!(f.take_a(), Some(String::from("x")));
+    assert_eq!(f.a, None);
+    assert_eq!(f.take_b(), Some(1));
+    assert_eq!(f.take_b(), None);
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(take_default(public))]
+pub struct Buffer {
+    text: String,
+    items: Vec<u32>,
+    map: HashMap<u8, u8>,
+    #[property(take(public))]
+    opt: Option<u32>,
+    other: Option<u32>,
+    #[property(take(public), take_default(prefix = "reset_"))]
+    both: Option<u32>,
+}
+
+fn main() {
+    let mut value = Buffer {
+        text: "abc".to_owned(),
+        items: vec![1],
+        opt: Some(1),
+        other: Some(2),
+        ..Default::default()
+    };
+    value.map.insert(1, 2);
+    assert_eq!(value.take_text(), "abc");
+    assert_eq!(value.text, "");
+    assert_eq!(value.take_items(), vec![1]);
+    assert!(value.items.is_empty());
+    assert_eq!(value.take_map().len(), 1);
+    assert_eq!(value.take_opt(), Some(1));
+    assert_eq!(value.take_other(), Some(2));
+    assert!(value.other.is_none());
+    value.both = Some(3);
+    assert_eq!(value.reset_both(), Some(3));
+    value.both = Some(4);
+    assert_eq!(value.take_both(), Some(4));
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(get(template = "raw_{}_value"))]
+struct Foo {
+    a: String,
+    #[property(get(prefix = "p_"))]
+    b: u8,
+    #[property(set(template = "{}_is"))]
+    c: u8,
+}
+fn main() {
+    let mut f = Foo {
+        a: "x".into(),
+        b: 1,
+        c: 2,
+    };
+    assert_eq!(f.raw_a_value(), "x");
+    assert_eq!(f.p_b(), 1);
+    f.c_is(3);
+    assert_eq!(f.raw_c_value(), 3);
+}
@@ -0,0 +1,14 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(toggle)]
+struct Foo {
+    on: bool,
+    n: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    assert!(f.toggle_on());
+    assert!(!f.toggle_on());
+    assert!(!f.on);
+}
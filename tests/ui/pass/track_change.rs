@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property)]
+#[property(set(track_change))]
+struct Foo {
+    #[property(set(clone_from, if_some, fmt))]
+    name: String,
+    #[property(set(min = "1", max = "9"))]
+    level: u8,
+    #[property(set(strip_option))]
+    nick: Option<String>,
+    #[property(with(public), set(vec))]
+    list: Vec<u8>,
+}
+fn main() {
+    let mut f = Foo {
+        name: "a".into(),
+        level: 1,
+        nick: None,
+        list: vec![],
+    };
+    assert!(!f.set_name("a"));
+    assert!(f.set_name("b"));
+    assert!(!f.set_name_from(&"b".to_owned()));
+    assert!(f.set_name_from(&"c".to_owned()));
+    assert!(!f.set_name_if_some(None::<&str>));
+    assert!(!f.set_name_if_some(Some("c")));
+    assert!(f.set_name_if_some(Some("d")));
+    assert!(!f.set_name_fmt(format_args!("{}", "d")));
+    assert!(f.set_name_fmt(format_args!("{}", 1)));
+    assert_eq!(f.name, "1");
+    assert_eq!(f.set_level(1), Ok(false));
+    assert_eq!(f.set_level(2), Ok(true));
+    assert!(f.set_level(10).is_err());
+    assert!(f.set_nick("x"));
+    assert!(!f.set_nick("x"));
+    assert!(f.set_nick_opt(None));
+    assert!(!f.set_nick_opt(None));
+    assert!(f.set_list(vec![1u8]));
+    assert!(!f.set_list_vec(vec![1]));
+    assert!(f.set_list_vec(vec![2]));
+    let f = f.with_list(vec![3u8]);
+    assert_eq!(f.list, vec![3]);
+}
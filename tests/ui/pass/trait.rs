@@ -0,0 +1,112 @@
+#![allow(non_camel_case_types)]
+
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(trait(name = "HasConfig", public), mut(disable), clr(disable))]
+#[property(into(public))]
+pub struct Config {
+    #[property(get(public), set(public))]
+    name: String,
+    #[property(get(public), set(public), push(public))]
+    ports: Vec<u16>,
+    #[property(get(public), set(public, type = "replace"))]
+    retries: u8,
+    #[property(skip)]
+    cache: u8,
+}
+
+#[derive(Property)]
+#[property(trait(name = "Named", only), get(public), set(disable), mut(disable))]
+pub struct Only {
+    name: String,
+}
+
+#[derive(Property)]
+#[property(trait(name = "r#dyn", only), get(public), set(disable), mut(disable))]
+pub struct Raw {
+    id: u8,
+}
+
+#[derive(Property, Default)]
+#[property(trait = "Plain")]
+pub struct Simple {
+    value: u8,
+}
+
+pub struct Mock;
+
+impl HasConfig for Mock {
+    fn name(&self) -> &str {
+        "mock"
+    }
+    fn set_name(&mut self, _: String) {}
+    fn ports(&self) -> &[u16] {
+        &[]
+    }
+    fn set_ports(&mut self, _: Vec<u16>) {}
+    fn push_ports<T: Into<u16>>(&mut self, _: T) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+    fn retries(&self) -> u8 {
+        0
+    }
+    fn set_retries(&mut self, _: u8) -> u8 {
+        0
+    }
+    fn into_name(self) -> String
+    where
+        Self: Sized,
+    {
+        String::new()
+    }
+    fn into_ports(self) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+    fn into_retries(self) -> u8
+    where
+        Self: Sized,
+    {
+        0
+    }
+}
+
+fn describe(config: &mut dyn HasConfig) -> String {
+    config.set_name("x".to_owned());
+    format!(
+        "{}:{}:{}",
+        config.name(),
+        config.ports().len(),
+        config.retries()
+    )
+}
+
+fn main() {
+    let mut c = Config::default();
+    c.set_ports(vec![1u16]);
+    assert_eq!(describe(&mut c), "x:1:0");
+    assert_eq!(describe(&mut Mock), "mock:0:0");
+    assert_eq!(c.set_retries(3), 0);
+    assert_eq!(c.retries(), 3);
+    // The inherent setters are not changed.
+    c.set_name("y").set_ports(Vec::<u16>::new());
+    assert_eq!(describe(&mut c), "x:0:3");
+    let mut s = Simple::default();
+    let plain: &mut dyn Plain = &mut s;
+    plain.set_value(2);
+    assert_eq!(plain.value(), 2);
+    s.set_value(3u8).set_value(4);
+    assert_eq!(s.value(), 4);
+    let _ = c.cache;
+    let o = Only {
+        name: "o".to_owned(),
+    };
+    assert_eq!(Named::name(&o), "o");
+    assert_eq!(r#dyn::id(&Raw { id: 7 }), 7);
+}
@@ -0,0 +1,49 @@
+use property::Property;
+fn trim(s: String) -> String {
+    s.trim().to_owned()
+}
+fn sorted(mut v: Vec<u32>) -> Vec<u32> {
+    v.sort();
+    v
+}
+fn double(n: u32) -> u32 {
+    n * 2
+}
+#[derive(Property)]
+struct Foo {
+    #[property(set(transform = "trim"))]
+    name: String,
+    #[property(set(transform = "trim", strip_option))]
+    nick: Option<String>,
+    #[property(set(transform = "sorted"))]
+    items: Vec<u32>,
+    #[property(set(transform = "double", per_element))]
+    doubled: Vec<u32>,
+    #[property(set(transform = "trim", per_element, strip_option))]
+    tags: Option<Vec<String>>,
+    #[property(set(transform = "double", max = "10"))]
+    level: u32,
+}
+fn main() {
+    let mut f = Foo {
+        name: String::new(),
+        nick: None,
+        items: vec![],
+        doubled: vec![],
+        tags: None,
+        level: 0,
+    };
+    f.set_name("  a ")
+        .set_nick(" b ")
+        .set_items(vec![3u32, 1, 2])
+        .set_doubled(vec![1u32, 2]);
+    f.set_tags(vec![" x", "y "]);
+    assert_eq!(f.name, "a");
+    assert_eq!(f.nick.as_deref(), Some("b"));
+    assert_eq!(f.items, vec![1, 2, 3]);
+    assert_eq!(f.doubled, vec![2, 4]);
+    assert_eq!(f.tags, Some(vec!["x".to_owned(), "y".to_owned()]));
+    assert!(f.set_level(5u32).is_ok());
+    assert!(f.set_level(6u32).is_err());
+    assert_eq!(f.level, 10);
+}
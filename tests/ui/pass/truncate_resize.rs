@@ -0,0 +1,25 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(get(disable), truncate(public))]
+pub struct Buffers {
+    #[property(resize(public))]
+    history: Vec<u32>,
+    text: String,
+}
+
+fn main() {
+    let mut value = Buffers {
+        history: vec![1, 2, 3],
+        text: "hello".to_owned(),
+    };
+    value.truncate_history(5);
+    assert_eq!(value.history, vec![1, 2, 3]);
+    value.truncate_history(2).truncate_text(2);
+    assert_eq!(value.history, vec![1, 2]);
+    assert_eq!(value.text, "he");
+    value.resize_history(4, 9);
+    assert_eq!(value.history, vec![1, 2, 9, 9]);
+    value.resize_history(1, 0);
+    assert_eq!(value.history, vec![1]);
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Debug, PartialEq)]
+pub struct MissingField(&'static str);
+#[derive(Property)]
+#[property(get(type = "try_ref"))]
+struct Foo {
+    #[property(get(error = "crate::MissingField"))]
+    host: Option<String>,
+    port: Option<u16>,
+    list: Option<Vec<u8>>,
+}
+fn main() {
+    let mut f = Foo {
+        host: None,
+        port: Some(1),
+        list: Some(vec![1]),
+    };
+    assert_eq!(f.host(), Err(MissingField("host")));
+    f.set_host("x");
+    assert_eq!(f.host(), Ok("x"));
+    assert_eq!(f.port(), Ok(&1));
+    assert_eq!(f.list(), Ok(&[1u8][..]));
+    f.clear_port();
+    assert_eq!(f.port(), Err("port"));
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+struct Opaque(u8);
+#[derive(Property)]
+#[property(update(public), mut(disable))]
+struct Foo {
+    count: u32,
+    list: Vec<u8>,
+    o: Opaque,
+}
+fn main() {
+    let mut f = Foo {
+        count: 0,
+        list: vec![],
+        o: Opaque(1),
+    };
+    let r = f.update_count(|c| {
+        *c += 1;
+        *c * 10
+    });
+    assert_eq!(r, 10);
+    f.update_list(|l| l.push(1));
+    assert_eq!(f.list, vec![1]);
+    f.update_o(|o| o.0 = 2);
+    assert_eq!(f.o.0, 2);
+}
@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+use property::Property;
+use std::convert::TryFrom;
+fn in_range(v: &u8) -> Result<(), String> {
+    if *v <= 100 {
+        Ok(())
+    } else {
+        Err(format!("{} out of range", v))
+    }
+}
+fn non_empty(v: &str) -> Result<(), &'static str> {
+    if v.is_empty() {
+        Err("empty")
+    } else {
+        Ok(())
+    }
+}
+#[derive(Debug, PartialEq)]
+enum MyErr {
+    Empty,
+    Conv,
+}
+impl From<&'static str> for MyErr {
+    fn from(_: &'static str) -> Self {
+        MyErr::Empty
+    }
+}
+impl From<std::num::TryFromIntError> for MyErr {
+    fn from(_: std::num::TryFromIntError) -> Self {
+        MyErr::Conv
+    }
+}
+fn small(v: &u8) -> Result<(), &'static str> {
+    if *v < 10 {
+        Ok(())
+    } else {
+        Err("x")
+    }
+}
+#[derive(Property, Default)]
+struct Foo {
+    #[property(set(validator = "in_range", error = "String"))]
+    level: u8,
+    #[property(set(validator = "non_empty", error = "&'static str", type = "replace"))]
+    name: Option<String>,
+    #[property(set(validator = "small", error = "MyErr", fallible, type = "own"))]
+    tiny: u8,
+}
+fn main() {
+    let mut f = Foo::default();
+    f.set_level(100u8).unwrap();
+    assert!(f.set_level(101u8).is_err());
+    assert_eq!(f.level, 100);
+    assert_eq!(f.set_name("a"), Ok(None));
+    assert_eq!(f.set_name(""), Err("empty"));
+    assert_eq!(f.name.as_deref(), Some("a"));
+    let _ = u8::try_from(1u32);
+    let f = f.set_tiny(3u32).unwrap();
+    assert_eq!(f.tiny, 3);
+    let f2 = Foo::default();
+    assert_eq!(f2.set_tiny(300u32).err(), Some(MyErr::Conv));
+    assert_eq!(Foo::default().set_tiny(30u32).err(), Some(MyErr::Empty));
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+use property::Property;
+#[derive(Property, Default)]
+#[property(with(public))]
+struct Foo {
+    a: u8,
+    b: String,
+    c: Vec<String>,
+    #[property(set(strip_option))]
+    d: Option<String>,
+    #[property(with(disable))]
+    e: u8,
+}
+fn main() {
+    let mut f = Foo::default()
+        .with_a(1u8)
+        .with_b("x")
+        .with_c(vec!["y"])
+        .with_d("z");
+    assert_eq!(f.a, 1);
+    f.set_a(2u8);
+    assert_eq!(f.a, 2);
+    let f = f.with_d_opt(None);
+    assert_eq!(f.d, None);
+    assert_eq!(f.b, "x");
+}
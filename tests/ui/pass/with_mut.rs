@@ -0,0 +1,35 @@
+use property::Property;
+
+#[derive(Property, Default)]
+#[property(mut(private), with_mut(public))]
+pub struct Scoped {
+    items: Vec<u32>,
+    raw: Option<u32>,
+    #[property(with_mut(flatten))]
+    flat: Option<u32>,
+    #[property(update(public))]
+    both: u32,
+}
+
+fn main() {
+    let mut value = Scoped::default();
+    let len = value.with_items_mut(|items| {
+        items.push(1);
+        items.len()
+    });
+    assert_eq!(len, 1);
+    value.with_raw_mut(|raw| *raw = Some(3));
+    assert_eq!(value.raw, Some(3));
+    assert_eq!(value.with_flat_mut(|x| *x += 1), None);
+    value.flat = Some(1);
+    assert_eq!(
+        value.with_flat_mut(|x| {
+            *x += 1;
+            *x
+        }),
+        Some(2)
+    );
+    value.update_both(|x| *x += 1);
+    value.with_both_mut(|x| *x += 1);
+    assert_eq!(value.both, 2);
+}
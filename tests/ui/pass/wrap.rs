@@ -0,0 +1,63 @@
+use property::Property;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+#[derive(Debug, PartialEq)]
+struct Config {
+    level: u8,
+}
+impl From<u8> for Config {
+    fn from(level: u8) -> Self {
+        Config { level }
+    }
+}
+#[derive(Property)]
+#[property(set(wrap))]
+struct Foo {
+    config: Arc<Config>,
+    #[property(set(type = "replace"))]
+    lock: Arc<Mutex<i32>>,
+    local: Rc<Config>,
+    name: Arc<str>,
+    #[property(set(validator = "check", error = "String"))]
+    checked: std::sync::Arc<Config>,
+}
+fn check(c: &Config) -> Result<(), String> {
+    if c.level > 9 {
+        Err("too high".to_owned())
+    } else {
+        Ok(())
+    }
+}
+fn main() {
+    let mut f = Foo {
+        config: Arc::new(Config { level: 0 }),
+        lock: Arc::new(Mutex::new(0)),
+        local: Rc::new(Config { level: 0 }),
+        name: Arc::from(""),
+        checked: Arc::new(Config { level: 0 }),
+    };
+    f.set_config(1u8)
+        .set_local(Config { level: 2 })
+        .set_name("abc");
+    assert_eq!(f.config.level, 1);
+    assert_eq!(f.local.level, 2);
+    assert_eq!(&*f.name, "abc");
+    let shared = Arc::new(Config { level: 3 });
+    f.set_config_shared(shared.clone());
+    assert!(Arc::ptr_eq(&shared, &f.config));
+    let old = f.set_lock(Mutex::new(5));
+    assert_eq!(*old.lock().unwrap(), 0);
+    let lock = Arc::new(Mutex::new(6));
+    let old = f.set_lock_shared(lock.clone());
+    assert_eq!(*old.lock().unwrap(), 5);
+    assert!(Arc::ptr_eq(&lock, &f.lock));
+    assert!(f.set_checked(Config { level: 10 }).is_err());
+    assert!(f.set_checked(4u8).is_ok());
+    assert_eq!(f.checked.level, 4);
+    assert!(f
+        .set_checked_shared(Arc::new(Config { level: 10 }))
+        .is_err());
+    assert_eq!(f.checked.level, 4);
+    assert!(f.set_checked_shared(shared.clone()).is_ok());
+    assert!(Arc::ptr_eq(&shared, &f.checked));
+}